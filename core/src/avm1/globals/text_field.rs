@@ -367,7 +367,7 @@ pub fn html_text<'gc>(
     this: EditText<'gc>,
     activation: &mut Activation<'_, 'gc>,
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(AvmString::new(activation.context.gc_context, this.html_text()).into())
+    Ok(AvmString::new(activation.context.gc_context, this.displayed_html_text()).into())
 }
 
 pub fn set_html_text<'gc>(
@@ -2,7 +2,7 @@ use crate::avm2::bytearray::ByteArrayStorage;
 use crate::avm2::object::{ByteArrayObject, TObject};
 use crate::avm2::ArrayObject;
 use crate::avm2::ArrayStorage;
-use crate::avm2::{Activation, Error, Object, Value};
+use crate::avm2::{Activation, ClassObject, Error, Object, QName, Value};
 use crate::string::AvmString;
 use enumset::EnumSet;
 use flash_lso::types::{AMFVersion, Element, Lso};
@@ -169,16 +169,19 @@ pub fn deserialize_value<'gc>(
             array.into()
         }
         AmfValue::Object(elements, class) => {
-            if let Some(class) = class {
-                if !class.name.is_empty() && class.name != "Object" {
-                    tracing::warn!("Deserializing class {:?} is not supported!", class);
+            let class_object = match class {
+                Some(class) if !class.name.is_empty() && class.name != "Object" => {
+                    let class_object = class_for_alias(activation, &class.name)?;
+                    if class_object.is_none() {
+                        tracing::warn!("No class is registered for the alias of {:?}", class);
+                    }
+                    class_object
                 }
-            }
+                _ => None,
+            };
 
-            let mut obj = activation
-                .avm2()
-                .classes()
-                .object
+            let mut obj = class_object
+                .unwrap_or_else(|| activation.avm2().classes().object)
                 .construct(activation, &[])?;
             for entry in elements {
                 let value = deserialize_value(activation, entry.value())?;
@@ -223,6 +226,32 @@ pub fn deserialize_value<'gc>(
     })
 }
 
+/// Find the class that `flash.net.registerClassAlias` registered under an alias.
+///
+/// The alias registry is the `flash.net` internal `_classLookups` object. It is
+/// read directly instead of calling `flash.net.getClassByAlias`, which throws
+/// for unknown aliases in Flash Player: here an unregistered alias just means
+/// a plain `Object` is created.
+/// The registered class was already resolved in the domain of the movie that
+/// registered it, so it is used as-is.
+fn class_for_alias<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    alias: &str,
+) -> Result<Option<ClassObject<'gc>>, Error<'gc>> {
+    let name = QName::new(activation.avm2().flash_net_internal, "_classLookups");
+    let class_lookups = activation
+        .avm2()
+        .playerglobals_domain
+        .get_defined_value(activation, name)?;
+    let Some(class_lookups) = class_lookups.as_object() else {
+        return Ok(None);
+    };
+
+    let alias = AvmString::new_utf8(activation.context.gc_context, alias);
+    let class = class_lookups.get_public_property(alias, activation)?;
+    Ok(class.as_object().and_then(|class| class.as_class_object()))
+}
+
 /// Deserializes a Lso into an object containing the properties stored
 pub fn deserialize_lso<'gc>(
     activation: &mut Activation<'_, 'gc>,
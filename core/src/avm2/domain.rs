@@ -7,6 +7,7 @@ use crate::avm2::script::Script;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::avm2::Multiname;
+use crate::avm2::Namespace;
 use crate::avm2::QName;
 use gc_arena::{Collect, GcCell, MutationContext};
 use ruffle_wstr::WStr;
@@ -42,6 +43,22 @@ struct DomainData<'gc> {
     pub domain_memory: Option<ByteArrayObject<'gc>>,
 }
 
+/// Look up a multiname among the definitions or classes of a domain.
+///
+/// Unlike `PropertyMap::get_with_ns_for_multiname`, the any namespace (`*`)
+/// matches every namespace here, as with `Multiname::contains_name`. When
+/// several namespaces define the same local name, the first one stored wins.
+fn get_with_ns_for_multiname<'a, 'gc, V>(
+    map: &'a PropertyMap<'gc, V>,
+    multiname: &Multiname<'gc>,
+) -> Option<(Namespace<'gc>, &'a V)> {
+    if !multiname.is_any_namespace() {
+        return map.get_with_ns_for_multiname(multiname);
+    }
+
+    map.get_with_ns_for_local_name(multiname.local_name()?)
+}
+
 impl<'gc> Domain<'gc> {
     /// Create a new domain with no parent.
     ///
@@ -95,6 +112,33 @@ impl<'gc> Domain<'gc> {
         self.0.read().parent
     }
 
+    pub fn ptr_eq(a: Domain<'gc>, b: Domain<'gc>) -> bool {
+        GcCell::ptr_eq(a.0, b.0)
+    }
+
+    /// Find the closest domain in this domain's chain (including itself) that
+    /// registered the given class.
+    ///
+    /// This is intended for debugging class resolution, as a same-named class
+    /// may also be registered in other domains of the chain.
+    pub fn find_class_domain(self, class: GcCell<'gc, Class<'gc>>) -> Option<Domain<'gc>> {
+        let name = class.read().name();
+        let mut domain = Some(self);
+
+        while let Some(current) = domain {
+            let read = current.0.read();
+            if let Some(registered) = read.classes.get(name) {
+                if GcCell::ptr_eq(*registered, class) {
+                    return Some(current);
+                }
+            }
+
+            domain = read.parent;
+        }
+
+        None
+    }
+
     /// Determine if something has been defined within the current domain (including parents)
     pub fn has_definition(self, name: QName<'gc>) -> bool {
         let read = self.0.read();
@@ -110,6 +154,34 @@ impl<'gc> Domain<'gc> {
         false
     }
 
+    /// Record a failed lookup, along with every domain that was searched.
+    ///
+    /// This is only available with the `avm_debug` feature. The failure is
+    /// emitted as a `domain_lookup` span with the `avm2_domain` tracing
+    /// target, which can be shown with e.g. `RUST_LOG=avm2_domain=debug`.
+    #[cfg(feature = "avm_debug")]
+    fn trace_failed_lookup(self, kind: &'static str, multiname: &Multiname<'gc>) {
+        let mut searched = Vec::new();
+        let mut domain = Some(self);
+        while let Some(current) = domain {
+            searched.push(format!("{:p}", current.0.as_ptr()));
+            domain = current.parent_domain();
+        }
+
+        let _span = tracing::debug_span!(
+            target: "avm2_domain",
+            "domain_lookup",
+            kind,
+            multiname = ?multiname,
+        )
+        .entered();
+        tracing::debug!(
+            target: "avm2_domain",
+            domains = ?searched,
+            "Name was not found in any searched domain"
+        );
+    }
+
     /// Determine if a class has been defined within the current domain (including parents)
     pub fn has_class(self, name: QName<'gc>) -> bool {
         let read = self.0.read();
@@ -136,7 +208,7 @@ impl<'gc> Domain<'gc> {
         let read = self.0.read();
 
         if let Some(name) = multiname.local_name() {
-            if let Some((ns, script)) = read.defs.get_with_ns_for_multiname(multiname) {
+            if let Some((ns, script)) = get_with_ns_for_multiname(&read.defs, multiname) {
                 let qname = QName::new(ns, name);
                 return Ok(Some((qname, *script)));
             }
@@ -154,8 +226,9 @@ impl<'gc> Domain<'gc> {
         multiname: &Multiname<'gc>,
     ) -> Result<Option<GcCell<'gc, Class<'gc>>>, Error<'gc>> {
         let read = self.0.read();
-        if let Some(class) = read.classes.get_for_multiname(multiname).copied() {
-            return Ok(Some(class));
+
+        if let Some((_, class)) = get_with_ns_for_multiname(&read.classes, multiname) {
+            return Ok(Some(*class));
         }
 
         if let Some(parent) = read.parent {
@@ -172,6 +245,11 @@ impl<'gc> Domain<'gc> {
     ) -> Result<Option<GcCell<'gc, Class<'gc>>>, Error<'gc>> {
         let class = self.get_class_inner(multiname)?;
 
+        #[cfg(feature = "avm_debug")]
+        if class.is_none() {
+            self.trace_failed_lookup("class", multiname);
+        }
+
         if let Some(class) = class {
             if let Some(param) = multiname.param() {
                 if !param.is_any_name() {
@@ -193,7 +271,14 @@ impl<'gc> Domain<'gc> {
         activation: &mut Activation<'_, 'gc>,
         multiname: &Multiname<'gc>,
     ) -> Result<(QName<'gc>, Script<'gc>), Error<'gc>> {
-        match self.get_defining_script(multiname)? {
+        let defining_script = self.get_defining_script(multiname)?;
+
+        #[cfg(feature = "avm_debug")]
+        if defining_script.is_none() {
+            self.trace_failed_lookup("definition", multiname);
+        }
+
+        match defining_script {
             Some(val) => Ok(val),
             None => Err(Error::AvmError(crate::avm2::error::reference_error(
                 activation,
@@ -284,6 +369,48 @@ impl<'gc> Domain<'gc> {
         self.0.write(mc).defs.insert(name, script);
     }
 
+    /// Export every definition of a script into the current application domain.
+    ///
+    /// This behaves like calling `export_definition` for each name, but only
+    /// walks up the parent chain once for the whole batch.
+    pub fn export_definitions(
+        &mut self,
+        names: Vec<QName<'gc>>,
+        script: Script<'gc>,
+        mc: MutationContext<'gc, '_>,
+    ) {
+        let names = self.filter_undefined(names, |read, name| read.defs.contains_key(name));
+        if names.is_empty() {
+            return;
+        }
+
+        let mut write = self.0.write(mc);
+        for name in names {
+            write.defs.insert(name, script);
+        }
+    }
+
+    /// Remove the names that are already defined in this domain or a parent,
+    /// walking up the parent chain only once.
+    fn filter_undefined(
+        self,
+        mut names: Vec<QName<'gc>>,
+        is_defined: impl Fn(&DomainData<'gc>, QName<'gc>) -> bool,
+    ) -> Vec<QName<'gc>> {
+        let mut domain = Some(self);
+        while let Some(current) = domain {
+            if names.is_empty() {
+                break;
+            }
+
+            let read = current.0.read();
+            names.retain(|name| !is_defined(&read, *name));
+            domain = read.parent;
+        }
+
+        names
+    }
+
     /// Export a class into the current application domain.
     ///
     /// This does nothing if the definition already exists in this domain or a parent.
@@ -294,6 +421,32 @@ impl<'gc> Domain<'gc> {
         self.0.write(mc).classes.insert(class.read().name(), class);
     }
 
+    /// Export a batch of classes into the current application domain.
+    ///
+    /// This behaves like calling `export_class` for each class, but only
+    /// walks up the parent chain once for the whole batch.
+    pub fn export_classes(
+        &self,
+        classes: Vec<GcCell<'gc, Class<'gc>>>,
+        mc: MutationContext<'gc, '_>,
+    ) {
+        let names = classes.iter().map(|class| class.read().name()).collect();
+        let mut names = self.filter_undefined(names, |read, name| read.classes.contains_key(name));
+        if names.is_empty() {
+            return;
+        }
+
+        let mut write = self.0.write(mc);
+        for class in classes {
+            let name = class.read().name();
+            // As with `export_class`, the first class exported under a name wins.
+            if let Some(index) = names.iter().position(|other| *other == name) {
+                names.swap_remove(index);
+                write.classes.insert(name, class);
+            }
+        }
+    }
+
     pub fn domain_memory(&self) -> ByteArrayObject<'gc> {
         self.0
             .read()
@@ -343,3 +496,125 @@ impl<'gc> PartialEq for Domain<'gc> {
 }
 
 impl<'gc> Eq for Domain<'gc> {}
+
+#[cfg(test)]
+mod tests {
+    use super::Domain;
+    use crate::avm2::class::Class;
+    use crate::avm2::method::Method;
+    use crate::avm2::object::ScriptObject;
+    use crate::avm2::script::Script;
+    use crate::avm2::{Namespace, QName, Value};
+    use crate::context::GcContext;
+    use crate::string::{AvmString, AvmStringInterner};
+    use gc_arena::{rootless_arena, GcCell, MutationContext};
+    use std::time::Instant;
+
+    fn names<'gc>(
+        context: &mut GcContext<'_, 'gc>,
+        package: &str,
+        count: usize,
+    ) -> Vec<QName<'gc>> {
+        let package = AvmString::new_utf8(context.gc_context, package);
+        let ns = Namespace::package(package, context);
+        (0..count)
+            .map(|i| {
+                let name = AvmString::new_utf8(context.gc_context, format!("Def{i}"));
+                QName::new(ns, name)
+            })
+            .collect()
+    }
+
+    fn class<'gc>(name: QName<'gc>, mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+        let init = Method::from_builtin(|_, _, _| Ok(Value::Undefined), "<test>", mc);
+        Class::new(name, None, init.clone(), init, mc)
+    }
+
+    #[test]
+    fn find_class_domain_returns_closest_domain() {
+        rootless_arena(|mc| {
+            let mut interner = AvmStringInterner::new();
+            let mut context = GcContext {
+                gc_context: mc,
+                interner: &mut interner,
+            };
+            let name = names(&mut context, "test", 1)[0];
+
+            let parent = Domain::uninitialized_domain(mc, None);
+            let child = Domain::uninitialized_domain(mc, Some(parent));
+            let grandchild = Domain::uninitialized_domain(mc, Some(child));
+
+            // Exporting to the child first, as the parent would otherwise shadow it.
+            let child_class = class(name, mc);
+            child.export_class(child_class, mc);
+            let parent_class = class(name, mc);
+            parent.export_class(parent_class, mc);
+
+            let found = grandchild.find_class_domain(child_class);
+            assert!(found.map_or(false, |found| Domain::ptr_eq(found, child)));
+            let found = grandchild.find_class_domain(parent_class);
+            assert!(found.map_or(false, |found| Domain::ptr_eq(found, parent)));
+            assert!(parent.find_class_domain(child_class).is_none());
+            assert!(grandchild.find_class_domain(class(name, mc)).is_none());
+        });
+    }
+
+    /// An empty domain below a chain of `depth` domains, each defining `count` names.
+    fn domain_chain<'gc>(
+        context: &mut GcContext<'_, 'gc>,
+        script: Script<'gc>,
+        depth: usize,
+        count: usize,
+    ) -> Domain<'gc> {
+        let mc = context.gc_context;
+        let mut domain = Domain::uninitialized_domain(mc, None);
+        for level in 0..depth {
+            domain.export_definitions(names(context, &format!("level{level}"), count), script, mc);
+            domain = Domain::uninitialized_domain(mc, Some(domain));
+        }
+        domain
+    }
+
+    /// Compares `export_definitions` against exporting the same names one at a time.
+    ///
+    /// This is a benchmark rather than a test, so it's ignored by default. Run it with
+    /// `cargo test -p ruffle_core --release -- --ignored export_definitions_batch --nocapture`.
+    #[test]
+    #[ignore]
+    fn export_definitions_batch() {
+        const DEPTH: usize = 8;
+        const COUNT: usize = 20_000;
+
+        rootless_arena(|mc| {
+            let mut interner = AvmStringInterner::new();
+            let mut context = GcContext {
+                gc_context: mc,
+                interner: &mut interner,
+            };
+            let globals = ScriptObject::custom_object(mc, None, None);
+            let script = Script::empty_script(mc, globals, Domain::uninitialized_domain(mc, None));
+
+            // Half of the exported names are already defined at the top of the chain.
+            let mut exported = names(&mut context, "level0", COUNT / 2);
+            exported.extend(names(&mut context, "exported", COUNT / 2));
+
+            let mut one_by_one = domain_chain(&mut context, script, DEPTH, COUNT);
+            let start = Instant::now();
+            for name in exported.iter().copied() {
+                one_by_one.export_definition(name, script, mc);
+            }
+            let one_by_one_time = start.elapsed();
+
+            let mut batched = domain_chain(&mut context, script, DEPTH, COUNT);
+            let start = Instant::now();
+            batched.export_definitions(exported, script, mc);
+            let batched_time = start.elapsed();
+
+            println!("export_definition: {one_by_one_time:?}");
+            println!("export_definitions: {batched_time:?}");
+
+            assert_eq!(one_by_one.get_defined_names().len(), COUNT / 2);
+            assert_eq!(batched.get_defined_names().len(), COUNT / 2);
+        });
+    }
+}
@@ -94,6 +94,7 @@ pub struct SystemClasses<'gc> {
     pub progressevent: ClassObject<'gc>,
     pub httpstatusevent: ClassObject<'gc>,
     pub textevent: ClassObject<'gc>,
+    pub focusevent: ClassObject<'gc>,
     pub errorevent: ClassObject<'gc>,
     pub ioerrorevent: ClassObject<'gc>,
     pub securityerrorevent: ClassObject<'gc>,
@@ -214,6 +215,7 @@ impl<'gc> SystemClasses<'gc> {
             progressevent: object,
             httpstatusevent: object,
             textevent: object,
+            focusevent: object,
             errorevent: object,
             ioerrorevent: object,
             securityerrorevent: object,
@@ -715,6 +717,7 @@ fn load_playerglobal<'gc>(
             ("flash.events", "Event", event),
             ("flash.events", "EventDispatcher", eventdispatcher),
             ("flash.events", "TextEvent", textevent),
+            ("flash.events", "FocusEvent", focusevent),
             ("flash.events", "ErrorEvent", errorevent),
             ("flash.events", "KeyboardEvent", keyboardevent),
             ("flash.events", "ProgressEvent", progressevent),
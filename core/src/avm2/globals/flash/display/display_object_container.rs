@@ -7,9 +7,9 @@ use crate::avm2::object::{Object, TObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::{ArrayObject, ArrayStorage, Error};
+use crate::avm2_stub_method;
 use crate::context::UpdateContext;
 use crate::display_object::{DisplayObject, TDisplayObject, TDisplayObjectContainer};
-use crate::{avm2_stub_getter, avm2_stub_method, avm2_stub_setter};
 use std::cmp::min;
 
 /// Implements `flash.display.DisplayObjectContainer`'s native instance constructor.
@@ -584,29 +584,32 @@ pub fn set_mouse_children<'gc>(
 }
 
 pub fn get_tab_children<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(
-        activation,
-        "flash.display.DisplayObjectContainer",
-        "tabChildren"
-    );
-
-    Ok(true.into())
+    if let Some(dobj) = this
+        .as_display_object()
+        .and_then(|this| this.as_container())
+    {
+        return Ok(dobj.raw_container().tab_children().into());
+    }
+    Ok(Value::Undefined)
 }
 
 pub fn set_tab_children<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(
-        activation,
-        "flash.display.DisplayObjectContainer",
-        "tabChildren"
-    );
+    if let Some(dobj) = this
+        .as_display_object()
+        .and_then(|this| this.as_container())
+    {
+        let tab_children = args.get_bool(0);
 
+        dobj.raw_container_mut(activation.context.gc_context)
+            .set_tab_children(tab_children);
+    }
     Ok(Value::Undefined)
 }
@@ -6,7 +6,6 @@ use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::display_object::{TDisplayObject, TInteractiveObject};
-use crate::{avm2_stub_getter, avm2_stub_setter};
 
 /// Implements `flash.display.InteractiveObject`'s native instance constructor.
 pub fn native_instance_init<'gc>(
@@ -118,66 +117,108 @@ pub fn set_context_menu<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `InteractiveObject.tabEnabled`'s getter.
 pub fn get_tab_enabled<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.InteractiveObject", "tabEnabled");
+    if let Some(int) = this
+        .as_display_object()
+        .and_then(|dobj| dobj.as_interactive())
+    {
+        return Ok(int.tab_enabled(&mut activation.context).into());
+    }
 
-    Ok(false.into())
+    Ok(Value::Undefined)
 }
 
+/// Implements `InteractiveObject.tabEnabled`'s setter.
 pub fn set_tab_enabled<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.InteractiveObject", "tabIndex");
+    if let Some(int) = this
+        .as_display_object()
+        .and_then(|dobj| dobj.as_interactive())
+    {
+        let value = args.get_bool(0);
+        int.set_tab_enabled(activation.context.gc_context, value);
+    }
 
     Ok(Value::Undefined)
 }
 
+/// Implements `InteractiveObject.tabIndex`'s getter.
 pub fn get_tab_index<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.InteractiveObject", "tabIndex");
+    if let Some(int) = this
+        .as_display_object()
+        .and_then(|dobj| dobj.as_interactive())
+    {
+        return Ok(int.tab_index().unwrap_or(-1).into());
+    }
 
-    Ok((-1).into())
+    Ok(Value::Undefined)
 }
 
+/// Implements `InteractiveObject.tabIndex`'s setter.
 pub fn set_tab_index<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.InteractiveObject", "tabIndex");
+    if let Some(int) = this
+        .as_display_object()
+        .and_then(|dobj| dobj.as_interactive())
+    {
+        let value = args.get_i32(activation, 0)?;
+        // Negative values remove the object from the explicit tab order.
+        let value = if value >= 0 { Some(value) } else { None };
+        int.set_tab_index(activation.context.gc_context, value);
+    }
 
     Ok(Value::Undefined)
 }
 
+/// Implements `InteractiveObject.focusRect`'s getter.
 pub fn get_focus_rect<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.InteractiveObject", "focusRect");
+    if let Some(int) = this
+        .as_display_object()
+        .and_then(|dobj| dobj.as_interactive())
+    {
+        return Ok(int.focus_rect().map_or(Value::Null, Value::Bool));
+    }
+
     Ok(Value::Null)
 }
 
+/// Implements `InteractiveObject.focusRect`'s setter.
 pub fn set_focus_rect<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    // NOTE: all values other than true or null are converted to false. (false/null do differ)
-
-    // let's only warn on true, as games sometimes just set focusRect to false for some reason.
-    if matches!(args.get(0), Some(Value::Bool(true))) {
-        avm2_stub_setter!(activation, "flash.display.InteractiveObject", "focusRect");
+    if let Some(int) = this
+        .as_display_object()
+        .and_then(|dobj| dobj.as_interactive())
+    {
+        // NOTE: all values other than true or null are converted to false. (false/null do differ)
+        let value = match args.get_value(0) {
+            Value::Undefined | Value::Null => None,
+            Value::Bool(true) => Some(true),
+            _ => Some(false),
+        };
+        int.set_focus_rect(activation.context.gc_context, value);
     }
 
-    Ok(Value::Null)
+    Ok(Value::Undefined)
 }
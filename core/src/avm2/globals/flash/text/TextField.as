@@ -123,6 +123,7 @@ package flash.text {
         public native function get selectionEndIndex(): int;
 
         public native function appendText(text:String):void;
+        public native function getCharIndexAtPoint(x:Number, y:Number):int;
         public native function getLineMetrics(lineIndex:int):TextLineMetrics;
         public native function getTextFormat(beginIndex:int = -1, endIndex:int = -1):TextFormat;
         public native function setTextFormat(format:TextFormat, beginIndex:int = -1, endIndex:int = -1):void;
@@ -10,7 +10,7 @@ use crate::display_object::{AutoSizeMode, EditText, TDisplayObject, TextSelectio
 use crate::html::TextFormat;
 use crate::string::AvmString;
 use crate::{avm2_stub_getter, avm2_stub_setter};
-use swf::Color;
+use swf::{Color, Point, Twips};
 
 pub fn text_field_allocator<'gc>(
     class: ClassObject<'gc>,
@@ -239,20 +239,33 @@ pub fn set_border_color<'gc>(
 }
 
 pub fn get_condense_white<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.text.TextField", "condenseWhite");
-    Ok(Value::Bool(false))
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        return Ok(this.condense_white().into());
+    }
+
+    Ok(Value::Undefined)
 }
 
 pub fn set_condense_white<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.text.TextField", "condenseWhite");
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let condense_white = args.get_bool(0);
+        this.set_condense_white(activation.context.gc_context, condense_white);
+    }
+
     Ok(Value::Undefined)
 }
 
@@ -365,7 +378,9 @@ pub fn get_html_text<'gc>(
         .as_display_object()
         .and_then(|this| this.as_edit_text())
     {
-        return Ok(AvmString::new(activation.context.gc_context, this.html_text()).into());
+        return Ok(
+            AvmString::new(activation.context.gc_context, this.displayed_html_text()).into(),
+        );
     }
 
     Ok(Value::Undefined)
@@ -752,20 +767,25 @@ pub fn replace_text<'gc>(
             .get(0)
             .cloned()
             .unwrap_or(Value::Undefined)
-            .coerce_to_u32(activation)?;
+            .coerce_to_i32(activation)?;
         let end_index = args
             .get(1)
             .cloned()
             .unwrap_or(Value::Undefined)
-            .coerce_to_u32(activation)?;
+            .coerce_to_i32(activation)?;
         let value = args.get_string(activation, 2)?;
 
-        this.replace_text(
-            begin_index as usize,
-            end_index as usize,
-            &value,
-            &mut activation.context,
-        );
+        // Flash clamps both indices into the text, and accepts them in either order.
+        let text_length = this.text_length();
+        let begin_index = begin_index.clamp(0, text_length as i32) as usize;
+        let end_index = end_index.clamp(0, text_length as i32) as usize;
+        let (begin_index, end_index) = if begin_index > end_index {
+            (end_index, begin_index)
+        } else {
+            (begin_index, end_index)
+        };
+
+        this.replace_text(begin_index, end_index, &value, &mut activation.context);
     }
 
     Ok(Value::Undefined)
@@ -881,6 +901,15 @@ pub fn set_text_format<'gc>(
                     .unwrap_or(&(-1).into())
                     .coerce_to_i32(activation)?;
 
+                if end_index < 0 {
+                    end_index = if begin_index < 0 {
+                        this.text_length() as i32
+                    } else {
+                        // With only a begin index, just that one character is formatted.
+                        begin_index + 1
+                    };
+                }
+
                 if begin_index < 0 {
                     begin_index = 0;
                 }
@@ -889,10 +918,6 @@ pub fn set_text_format<'gc>(
                     return Err("RangeError: The supplied index is out of bounds.".into());
                 }
 
-                if end_index < 0 {
-                    end_index = this.text_length() as i32;
-                }
-
                 if end_index as usize > this.text_length() {
                     return Err("RangeError: The supplied index is out of bounds.".into());
                 }
@@ -1148,6 +1173,26 @@ pub fn get_line_metrics<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn get_char_index_at_point<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let x = args.get_f64(activation, 0)?;
+        let y = args.get_f64(activation, 1)?;
+        let index =
+            this.char_index_at_point(Point::new(Twips::from_pixels(x), Twips::from_pixels(y)));
+
+        return Ok(index.map_or(-1, |index| index as i32).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn get_bottom_scroll_v<'gc>(
     _activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -1322,18 +1367,34 @@ pub fn set_mouse_wheel_enabled<'gc>(
 
 pub fn get_restrict<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.text.TextField", "restrict");
-    Ok(Value::Null)
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        return Ok(match this.restrict() {
+            Some(value) => AvmString::new(activation.context.gc_context, value).into(),
+            None => Value::Null,
+        });
+    }
+
+    Ok(Value::Undefined)
 }
 
 pub fn set_restrict<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.text.TextField", "restrict");
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let value = args.try_get_string(activation, 0)?;
+        this.set_restrict(value.as_deref(), &mut activation.context);
+    }
+
     Ok(Value::Undefined)
 }
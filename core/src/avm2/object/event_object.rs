@@ -145,6 +145,71 @@ impl<'gc> EventObject<'gc> {
             .unwrap() // we don't expect to break here
     }
 
+    pub fn text_event<S>(
+        activation: &mut Activation<'_, 'gc>,
+        event_type: S,
+        text: AvmString<'gc>,
+        bubbles: bool,
+        cancelable: bool,
+    ) -> Object<'gc>
+    where
+        S: Into<AvmString<'gc>>,
+    {
+        let event_type: AvmString<'gc> = event_type.into();
+
+        let text_event_cls = activation.avm2().classes().textevent;
+        text_event_cls
+            .construct(
+                activation,
+                &[
+                    event_type.into(),
+                    // bubbles
+                    bubbles.into(),
+                    // cancelable
+                    cancelable.into(),
+                    // text
+                    text.into(),
+                ],
+            )
+            .unwrap() // we don't expect to break here
+    }
+
+    pub fn focus_event<S>(
+        activation: &mut Activation<'_, 'gc>,
+        event_type: S,
+        cancelable: bool,
+        related_object: Option<InteractiveObject<'gc>>,
+        key_code: u32,
+    ) -> Object<'gc>
+    where
+        S: Into<AvmString<'gc>>,
+    {
+        let event_type: AvmString<'gc> = event_type.into();
+        let shift_key = activation.context.input.is_key_down(KeyCode::Shift);
+
+        let focus_event_cls = activation.avm2().classes().focusevent;
+        focus_event_cls
+            .construct(
+                activation,
+                &[
+                    event_type.into(),
+                    // bubbles
+                    true.into(),
+                    // cancelable
+                    cancelable.into(),
+                    // relatedObject
+                    related_object
+                        .map(|o| o.as_displayobject().object2())
+                        .unwrap_or(Value::Null),
+                    // shiftKey
+                    shift_key.into(),
+                    // keyCode
+                    key_code.into(),
+                ],
+            )
+            .unwrap() // we don't expect to break here
+    }
+
     pub fn net_status_event<S>(
         activation: &mut Activation<'_, 'gc>,
         event_type: S,
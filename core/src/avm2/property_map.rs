@@ -97,6 +97,17 @@ impl<'gc, V> PropertyMap<'gc, V> {
         }
     }
 
+    /// Get the first value stored under a local name, in any namespace.
+    pub fn get_with_ns_for_local_name(
+        &self,
+        local_name: AvmString<'gc>,
+    ) -> Option<(Namespace<'gc>, &V)> {
+        self.0
+            .get(&local_name)
+            .and_then(|bucket| bucket.first())
+            .map(|(ns, v)| (*ns, v))
+    }
+
     pub fn get_mut(&mut self, name: QName<'gc>) -> Option<&mut V> {
         if let Some(bucket) = self.0.get_mut(&name.local_name()) {
             if let Some((_, old_value)) = bucket.iter_mut().find(|(n, _)| *n == name.namespace()) {
@@ -488,20 +488,25 @@ impl<'gc> Script<'gc> {
             .ok_or_else(|| "LoadError: Script index not valid".into());
         let script = script?;
 
+        let mut names = Vec::with_capacity(script.traits.len());
+        let mut classes = Vec::new();
         for abc_trait in script.traits.iter() {
             let newtrait = Trait::from_abc_trait(unit, abc_trait, activation)?;
-            write
-                .domain
-                .export_definition(newtrait.name(), *self, activation.context.gc_context);
+            names.push(newtrait.name());
             if let TraitKind::Class { class, .. } = newtrait.kind() {
-                write
-                    .domain
-                    .export_class(*class, activation.context.gc_context);
+                classes.push(*class);
             }
 
             write.traits.push(newtrait);
         }
 
+        write
+            .domain
+            .export_definitions(names, *self, activation.context.gc_context);
+        write
+            .domain
+            .export_classes(classes, activation.context.gc_context);
+
         Ok(())
     }
 
@@ -702,3 +702,56 @@ impl Default for SoundTransform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::swf::{AudioCompression, Sound, SoundEvent, SoundFormat, SoundInfo};
+    use crate::player::PlayerBuilder;
+    use crate::tag_utils::SwfMovie;
+
+    fn sound_info(event: SoundEvent) -> SoundInfo {
+        SoundInfo {
+            event,
+            in_sample: None,
+            out_sample: None,
+            num_loops: 1,
+            envelope: None,
+        }
+    }
+
+    /// Button sounds share their sync handling with timeline sounds.
+    #[test]
+    fn start_sound_with_sync() {
+        let player = PlayerBuilder::new().with_movie(SwfMovie::empty(10)).build();
+        let mut player = player.lock().unwrap();
+        player.mutate_with_update_context(|context| {
+            let sound = context
+                .audio
+                .register_sound(&Sound {
+                    id: 1,
+                    format: SoundFormat {
+                        compression: AudioCompression::Uncompressed,
+                        sample_rate: 44100,
+                        is_stereo: false,
+                        is_16_bit: true,
+                    },
+                    num_samples: 44100,
+                    data: &[],
+                })
+                .unwrap();
+
+            // "Start" sounds aren't restarted while they're still playing.
+            context.start_sound_with_sync(sound, &sound_info(SoundEvent::Start), None);
+            context.start_sound_with_sync(sound, &sound_info(SoundEvent::Start), None);
+            assert_eq!(context.audio_manager.sounds.len(), 1);
+
+            // "Event" sounds always play another instance.
+            context.start_sound_with_sync(sound, &sound_info(SoundEvent::Event), None);
+            assert_eq!(context.audio_manager.sounds.len(), 2);
+
+            // "Stop" stops every instance of the sound.
+            context.start_sound_with_sync(sound, &sound_info(SoundEvent::Stop), None);
+            assert!(context.audio_manager.sounds.is_empty());
+        });
+    }
+}
@@ -12,7 +12,7 @@ use crate::display_object::TDisplayObject;
 use gc_arena::MutationContext;
 use ruffle_render::bitmap::{PixelRegion, PixelSnapping};
 use ruffle_render::commands::{CommandHandler, CommandList};
-use ruffle_render::filters::Filter;
+use ruffle_render::filters::{blur, Filter};
 use ruffle_render::matrix::Matrix;
 use ruffle_render::quality::StageQuality;
 use ruffle_render::transform::Transform;
@@ -1182,6 +1182,19 @@ pub fn apply_filter<'gc>(
             return;
         }
 
+        if let Filter::BlurFilter(filter) = &filter {
+            if blur_on_cpu(
+                context.gc_context,
+                source,
+                target,
+                source_region,
+                dest_region,
+                filter,
+            ) {
+                return;
+            }
+        }
+
         // Until we support these filters, treat this like a copy
         copy_on_cpu(
             context.gc_context,
@@ -1216,6 +1229,60 @@ pub fn apply_filter<'gc>(
     }
 }
 
+/// Blurs the given region of `source` into `dest`, for renderers that can't apply filters.
+///
+/// Returns `false` if the filter has no effect, in which case nothing is written.
+fn blur_on_cpu<'gc>(
+    context: MutationContext<'gc, '_>,
+    source: BitmapDataWrapper<'gc>,
+    dest: BitmapDataWrapper<'gc>,
+    source_region: PixelRegion,
+    dest_region: PixelRegion,
+    filter: &swf::BlurFilter,
+) -> bool {
+    // The blurred buffer is laid out with the dimensions of the area being written.
+    let width = dest_region.width();
+    let height = dest_region.height();
+    let blurred = {
+        let source_read =
+            source.read_area(PixelRegion::for_whole_size(source.width(), source.height()));
+        let pixels: Vec<u8> = source_read
+            .pixels()
+            .iter()
+            .flat_map(|color| [color.red(), color.green(), color.blue(), color.alpha()])
+            .collect();
+        blur::apply_blur_on_cpu(
+            filter,
+            &pixels,
+            source_read.width(),
+            source_read.height(),
+            (source_region.x_min, source_region.y_min),
+            (width, height),
+        )
+    };
+    let Some(blurred) = blurred else {
+        return false;
+    };
+
+    let dest = dest.sync();
+    let mut write = dest.write(context);
+    let opaque = !write.transparency();
+    for y in 0..height {
+        for x in 0..width {
+            let i = ((y * width + x) * 4) as usize;
+            let mut color = Color::argb(blurred[i + 3], blurred[i], blurred[i + 1], blurred[i + 2]);
+            if opaque {
+                // The blur works on premultiplied colors, which an opaque bitmap can't store.
+                color = color.to_un_multiplied_alpha().with_alpha(255);
+            }
+            write.set_pixel32_raw(dest_region.x_min + x, dest_region.y_min + y, color);
+        }
+    }
+    write.set_cpu_dirty(dest_region);
+
+    true
+}
+
 #[allow(clippy::too_many_arguments)]
 fn copy_on_cpu<'gc>(
     context: MutationContext<'gc, '_>,
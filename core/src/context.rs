@@ -268,6 +268,34 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             .start_sound(self.audio, sound, settings, owner, avm1_object)
     }
 
+    /// Start a timeline or button sound, honoring the event type of its `SoundInfo`.
+    ///
+    /// The event type is controlled by the "Sync" setting in the Flash IDE.
+    pub fn start_sound_with_sync(
+        &mut self,
+        sound: SoundHandle,
+        settings: &swf::SoundInfo,
+        owner: Option<DisplayObject<'gc>>,
+    ) {
+        use swf::SoundEvent;
+        match settings.event {
+            // "Event" sounds always play, independent of the timeline.
+            SoundEvent::Event => {
+                let _ = self.start_sound(sound, settings, owner, None);
+            }
+
+            // "Start" sounds only play if an instance of the same sound is not already playing.
+            SoundEvent::Start => {
+                if !self.is_sound_playing_with_handle(sound) {
+                    let _ = self.start_sound(sound, settings, owner, None);
+                }
+            }
+
+            // "Stop" stops any active instances of a given sound.
+            SoundEvent::Stop => self.stop_sounds_with_handle(sound),
+        }
+    }
+
     pub fn attach_avm2_sound_channel(
         &mut self,
         instance: SoundInstanceHandle,
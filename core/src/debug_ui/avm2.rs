@@ -1,5 +1,5 @@
 use crate::avm2::property::Property;
-use crate::avm2::{Activation, ClassObject, Error, Namespace, Object, TObject, Value};
+use crate::avm2::{Activation, ClassObject, Domain, Error, Namespace, Object, TObject, Value};
 use crate::context::UpdateContext;
 use crate::debug_ui::display_object::open_display_object_button;
 use crate::debug_ui::handle::{AVM2ObjectHandle, DisplayObjectHandle};
@@ -191,6 +191,27 @@ impl Avm2ObjectWindow {
                 ui.text_edit_singleline(&mut name.local_name().to_string().as_str());
                 ui.end_row();
 
+                ui.label("Domain");
+                let domain = class.class_scope().domain().find_class_domain(definition);
+                ui.label(match domain {
+                    None => "Unregistered".to_string(),
+                    Some(domain) if domain.is_playerglobals_domain(activation) => {
+                        "Player Globals".to_string()
+                    }
+                    Some(domain) if Domain::ptr_eq(domain, activation.avm2().stage_domain()) => {
+                        "Stage".to_string()
+                    }
+                    Some(mut domain) => {
+                        let mut depth = 0;
+                        while let Some(parent) = domain.parent_domain() {
+                            domain = parent;
+                            depth += 1;
+                        }
+                        format!("Movie (depth {depth})")
+                    }
+                });
+                ui.end_row();
+
                 ui.label("Super Chain");
                 ui.vertical(|ui| {
                     let mut superclass = Some(class);
@@ -447,6 +447,10 @@ impl<'gc> TInteractiveObject<'gc> for Avm1Button<'gc> {
         self.into()
     }
 
+    fn tab_enabled_default(self, _context: &mut UpdateContext<'_, 'gc>) -> bool {
+        true
+    }
+
     fn filter_clip_event(
         self,
         _context: &mut UpdateContext<'_, 'gc>,
@@ -617,7 +621,8 @@ impl<'gc> Avm1ButtonData<'gc> {
                 .library_for_movie_mut(self.movie())
                 .get_sound(*id)
             {
-                let _ = context.start_sound(sound_handle, sound_info, None, None);
+                // Button sounds honor the same sync settings as timeline sounds.
+                context.start_sound_with_sync(sound_handle, sound_info, None);
             }
         }
     }
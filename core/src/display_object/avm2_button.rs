@@ -81,6 +81,12 @@ pub struct Avm2ButtonData<'gc> {
     /// All buttons start out not needing AVM2 initialization.
     needs_avm2_initialization: bool,
 
+    /// If constructing or initializing the AVM2 side of this button threw an
+    /// error.
+    ///
+    /// Failed buttons are not constructed again on later frames.
+    avm2_construction_failed: bool,
+
     has_focus: bool,
     enabled: bool,
     use_hand_cursor: bool,
@@ -124,6 +130,7 @@ impl<'gc> Avm2Button<'gc> {
                 object: None,
                 needs_frame_construction: construct_blank_states,
                 needs_avm2_initialization: false,
+                avm2_construction_failed: false,
                 tracking: if button.is_track_as_menu {
                     ButtonTracking::Menu
                 } else {
@@ -297,6 +304,9 @@ impl<'gc> Avm2Button<'gc> {
         if let Some(state) = self.get_state_child(state.into()) {
             state.set_parent(context, Some(self.into()));
         }
+        drop(button);
+
+        self.invalidate_cached_bitmap(context.gc_context);
     }
 
     /// Get the display object that represents a particular button state.
@@ -344,6 +354,8 @@ impl<'gc> Avm2Button<'gc> {
         }
 
         if is_cur_state {
+            self.invalidate_cached_bitmap(context.gc_context);
+
             if let Some(child) = child {
                 dispatch_added_event(self.into(), child, child_was_on_stage, context);
             }
@@ -399,6 +411,11 @@ impl<'gc> Avm2Button<'gc> {
     pub fn set_avm2_class(self, mc: MutationContext<'gc, '_>, class: Avm2ClassObject<'gc>) {
         self.0.write(mc).class = class;
     }
+
+    /// Whether the AVM2 class of this button threw while being constructed.
+    pub fn avm2_construction_failed(self) -> bool {
+        self.0.read().avm2_construction_failed
+    }
 }
 
 impl<'gc> TDisplayObject<'gc> for Avm2Button<'gc> {
@@ -479,13 +496,17 @@ impl<'gc> TDisplayObject<'gc> for Avm2Button<'gc> {
             over_state.construct_frame(context);
         }
 
-        let needs_avm2_construction = self.0.read().object.is_none();
+        let needs_avm2_construction =
+            self.0.read().object.is_none() && !self.0.read().avm2_construction_failed;
         let class = self.0.read().class;
         if needs_avm2_construction {
             let mut activation = Avm2Activation::from_nothing(context.reborrow());
             match Avm2StageObject::for_display_object(&mut activation, (*self).into(), class) {
                 Ok(object) => self.0.write(context.gc_context).object = Some(object.into()),
-                Err(e) => tracing::error!("Got {} when constructing AVM2 side of button", e),
+                Err(e) => {
+                    tracing::error!("Got {} when constructing AVM2 side of button", e);
+                    self.0.write(context.gc_context).avm2_construction_failed = true;
+                }
             };
 
             self.on_construction_complete(context);
@@ -577,6 +598,7 @@ impl<'gc> TDisplayObject<'gc> for Avm2Button<'gc> {
 
                 if let Err(e) = result {
                     tracing::error!("Got {} when constructing AVM2 side of button", e);
+                    self.0.write(context.gc_context).avm2_construction_failed = true;
                 }
             }
         }
@@ -609,6 +631,11 @@ impl<'gc> TDisplayObject<'gc> for Avm2Button<'gc> {
         let current_state = self.get_state_child(state.into());
 
         if let Some(state) = current_state {
+            // State children aren't in a render list, so we have to clear
+            // their invalidation flag ourselves. Otherwise, any later changes
+            // to the child (such as it resizing) would stop propagating up to
+            // this button's bitmap cache.
+            state.pre_render(context);
             state.render(context);
         }
     }
@@ -622,7 +649,10 @@ impl<'gc> TDisplayObject<'gc> for Avm2Button<'gc> {
         // Get self bounds
         let mut bounds = *matrix * self.self_bounds();
 
-        // Add the bounds of the child, dictated by current state
+        // Add the bounds of the child, dictated by current state.
+        // The hit area is never included, even if it is the only content of the
+        // button: Flash Player reports empty bounds (and a zero `width`/`height`)
+        // for such "invisible" buttons, which still respond to the mouse.
         let state = self.0.read().state;
         if let Some(child) = self.get_state_child(state.into()) {
             let matrix = *matrix * *child.base().matrix();
@@ -719,6 +749,10 @@ impl<'gc> TInteractiveObject<'gc> for Avm2Button<'gc> {
         self.into()
     }
 
+    fn tab_enabled_default(self, _context: &mut UpdateContext<'_, 'gc>) -> bool {
+        true
+    }
+
     fn filter_clip_event(
         self,
         _context: &mut UpdateContext<'_, 'gc>,
@@ -795,7 +829,14 @@ impl<'gc> TInteractiveObject<'gc> for Avm2Button<'gc> {
         require_button_mode: bool,
     ) -> Avm2MousePick<'gc> {
         // The button is hovered if the mouse is over any child nodes.
-        if self.visible() && self.mouse_enabled() {
+        // If it's not mouse enabled, it still counts as a hit for its parent.
+        let this_hit = if self.mouse_enabled() {
+            Avm2MousePick::Hit((*self).into())
+        } else {
+            Avm2MousePick::PropagateToParent
+        };
+
+        if self.visible() {
             let state = self.0.read().state;
             let state_child = self.get_state_child(state.into());
 
@@ -805,8 +846,15 @@ impl<'gc> TInteractiveObject<'gc> for Avm2Button<'gc> {
                     .map(|c| c.mouse_pick_avm2(context, point, require_button_mode));
                 match mouse_pick {
                     None | Some(Avm2MousePick::Miss) => {}
+                    // A button nested inside one of our states handles the mouse by itself,
+                    // without this button also changing state.
+                    Some(Avm2MousePick::Hit(target))
+                        if target.as_displayobject().as_avm2_button().is_some() =>
+                    {
+                        return Avm2MousePick::Hit(target)
+                    }
                     // Selecting a child of a button is equivalent to selecting the button itself
-                    _ => return Avm2MousePick::Hit((*self).into()),
+                    _ => return this_hit,
                 };
             }
 
@@ -823,7 +871,7 @@ impl<'gc> TInteractiveObject<'gc> for Avm2Button<'gc> {
                     }
                 }
                 if hit_area.hit_test_shape(context, point, HitTestOptions::MOUSE_PICK) {
-                    return Avm2MousePick::Hit((*self).into());
+                    return this_hit;
                 }
             }
         }
@@ -831,8 +879,7 @@ impl<'gc> TInteractiveObject<'gc> for Avm2Button<'gc> {
     }
 
     fn mouse_cursor(self, _context: &mut UpdateContext<'_, 'gc>) -> MouseCursor {
-        // TODO: Should we also need to check for the `enabled` property like AVM1 buttons?
-        if self.use_hand_cursor() {
+        if self.use_hand_cursor() && self.enabled() {
             MouseCursor::Hand
         } else {
             MouseCursor::Arrow
@@ -848,7 +895,8 @@ impl<'gc> Avm2ButtonData<'gc> {
                 .library_for_movie_mut(self.movie())
                 .get_sound(*id)
             {
-                let _ = context.start_sound(sound_handle, sound_info, None, None);
+                // Button sounds honor the same sync settings as timeline sounds.
+                context.start_sound_with_sync(sound_handle, sound_info, None);
             }
         }
     }
@@ -20,10 +20,11 @@ use crate::display_object::interactive::{
 use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, TDisplayObject};
 use crate::drawing::Drawing;
 use crate::events::{ClipEvent, ClipEventResult, TextControlCode};
-use crate::font::{round_down_to_pixel, Glyph, TextRenderSettings};
+use crate::font::{round_down_to_pixel, Font, Glyph, TextRenderSettings};
 use crate::html::{BoxBounds, FormatSpans, LayoutBox, LayoutContent, LayoutMetrics, TextFormat};
 use crate::prelude::*;
 use crate::string::{utils as string_utils, AvmString, SwfStrExt as _, WStr, WString};
+use crate::stub::Stub;
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::{AvmObject, Instantiator};
 use chrono::Utc;
@@ -32,7 +33,7 @@ use gc_arena::{Collect, Gc, GcCell, MutationContext};
 use ruffle_render::commands::CommandHandler;
 use ruffle_render::shape_utils::DrawCommand;
 use ruffle_render::transform::Transform;
-use std::{cell::Ref, cell::RefMut, sync::Arc};
+use std::{cell::Ref, cell::RefMut, ops::Range, ops::RangeInclusive, sync::Arc};
 use swf::{Color, ColorTransform, Twips};
 
 use super::interactive::Avm2MousePick;
@@ -147,6 +148,11 @@ pub struct EditTextData<'gc> {
     /// Doesn't affect script-triggered modifications.
     max_chars: i32,
 
+    /// The characters that can be manually input by the user, or `None` if
+    /// there is no restriction.
+    /// Doesn't affect script-triggered modifications.
+    restrict: Option<EditTextRestrict>,
+
     /// Flags indicating the text field's settings.
     flags: EditTextFlag,
 }
@@ -199,7 +205,80 @@ fn get_line_data(layout: &[LayoutBox]) -> Vec<LineData> {
     line_data
 }
 
+/// Slant a glyph's matrix to the right, to imitate an italic variant of a font.
+///
+/// Glyphs are drawn upwards from the baseline (towards negative y), so a
+/// negative shear moves their upper parts to the right while the baseline
+/// stays in place.
+fn synthesize_italic(matrix: Matrix) -> Matrix {
+    const SHEAR: Matrix = Matrix {
+        c: -0.2,
+        ..Matrix::IDENTITY
+    };
+
+    matrix * SHEAR
+}
+
+/// Whether text in the given format has to be slanted to look italic, because the font it is
+/// drawn with has no italic variant.
+///
+/// This is the case for embedded fonts as well as device fonts: when a movie only embeds the
+/// regular face of a font, italic text is slanted rather than drawn upright. There is no such
+/// fallback for bold text.
+fn is_faux_italic(tf: &TextFormat, font: Font<'_>) -> bool {
+    tf.italic == Some(true) && !font.descriptor().italic()
+}
+
+/// Lower HTML into text spans, reporting any tags that had to be ignored as stubs.
+fn html_to_text_spans(
+    context: &mut UpdateContext<'_, '_>,
+    html: &WStr,
+    default_format: TextFormat,
+    is_multiline: bool,
+    condense_white: bool,
+) -> FormatSpans {
+    let (text_spans, unsupported_tags) = FormatSpans::from_html_with_unsupported_tags(
+        html,
+        default_format,
+        is_multiline,
+        condense_white,
+    );
+    for tag in unsupported_tags {
+        let name = String::from_utf8_lossy(&tag);
+        context
+            .stub_tracker
+            .encounter(&Stub::Other(format!("HTML tag <{name}>").into()));
+    }
+    text_spans
+}
+
+impl<'gc> EditTextData<'gc> {
+    /// How far the text is shifted up by the current `scroll` line.
+    fn scroll_offset(&self) -> Twips {
+        if self.scroll > 1 {
+            self.line_data
+                .get(self.scroll - 1)
+                .map_or(Twips::ZERO, |line| line.offset)
+        } else {
+            Twips::ZERO
+        }
+    }
+
+    /// Convert a position in the text field's local space to the space of its layout boxes,
+    /// taking the padding and the current scroll position into account.
+    fn local_to_layout(&self, mut position: Point<Twips>) -> Point<Twips> {
+        position.x +=
+            Twips::from_pixels(EditText::INTERNAL_PADDING) + Twips::from_pixels(self.hscroll);
+        position.y += Twips::from_pixels(EditText::INTERNAL_PADDING) + self.scroll_offset();
+        position
+    }
+}
+
 impl<'gc> EditText<'gc> {
+    /// How many pixels a drag selection scrolls sideways each frame while the
+    /// mouse is held to the left or right of the field.
+    const DRAG_HSCROLL_STEP: f64 = 8.0;
+
     /// Creates a new `EditText` from an SWF `DefineEditText` tag.
     pub fn from_swf_tag(
         context: &mut UpdateContext<'_, 'gc>,
@@ -211,7 +290,13 @@ impl<'gc> EditText<'gc> {
         let text = swf_tag.initial_text().unwrap_or_default().decode(encoding);
 
         let mut text_spans = if swf_tag.is_html() {
-            FormatSpans::from_html(&text, default_format, swf_tag.is_multiline())
+            html_to_text_spans(
+                context,
+                &text,
+                default_format,
+                swf_tag.is_multiline(),
+                false,
+            )
         } else {
             FormatSpans::from_text(text.into_owned(), default_format)
         };
@@ -297,6 +382,7 @@ impl<'gc> EditText<'gc> {
                 line_data,
                 scroll: 1,
                 max_chars: swf_tag.max_length().unwrap_or_default() as i32,
+                restrict: None,
             },
         ));
 
@@ -350,7 +436,12 @@ impl<'gc> EditText<'gc> {
     pub fn set_text(self, text: &WStr, context: &mut UpdateContext<'_, 'gc>) {
         let mut edit_text = self.0.write(context.gc_context);
         let default_format = edit_text.text_spans.default_format().clone();
-        edit_text.text_spans = FormatSpans::from_text(text.into(), default_format);
+        let text_spans = FormatSpans::from_text(text.into(), default_format);
+        if edit_text.text_spans.has_same_content(&text_spans) {
+            // Nothing changed, so keep the current layout and scroll position.
+            return;
+        }
+        edit_text.text_spans = text_spans;
         drop(edit_text);
 
         self.relayout(context);
@@ -365,15 +456,32 @@ impl<'gc> EditText<'gc> {
         }
     }
 
+    /// The `htmlText` as seen by ActionScript.
+    ///
+    /// Unlike `html_text`, the text of non-HTML password fields is masked.
+    pub fn displayed_html_text(self) -> WString {
+        if self.is_html() {
+            self.0.read().text_spans.to_html()
+        } else {
+            self.0.read().text_spans.displayed_text().into()
+        }
+    }
+
     pub fn set_html_text(self, text: &WStr, context: &mut UpdateContext<'_, 'gc>) {
         if self.is_html() {
+            let read = self.0.read();
+            let default_format = read.text_spans.default_format().clone();
+            let is_multiline = read.flags.contains(EditTextFlag::MULTILINE);
+            let condense_white = read.flags.contains(EditTextFlag::CONDENSE_WHITE);
+            drop(read);
+
+            let text_spans =
+                html_to_text_spans(context, text, default_format, is_multiline, condense_white);
             let mut write = self.0.write(context.gc_context);
-            let default_format = write.text_spans.default_format().clone();
-            write.text_spans = FormatSpans::from_html(
-                text,
-                default_format,
-                write.flags.contains(EditTextFlag::MULTILINE),
-            );
+            if write.text_spans.has_same_content(&text_spans) {
+                return;
+            }
+            write.text_spans = text_spans;
             drop(write);
 
             self.relayout(context);
@@ -456,6 +564,21 @@ impl<'gc> EditText<'gc> {
         self.relayout(context);
     }
 
+    pub fn condense_white(self) -> bool {
+        self.0.read().flags.contains(EditTextFlag::CONDENSE_WHITE)
+    }
+
+    /// Set whether whitespace in HTML text should be condensed.
+    ///
+    /// This only affects text set after this call; already-stored text is
+    /// not condensed retroactively.
+    pub fn set_condense_white(self, gc_context: MutationContext<'gc, '_>, condense_white: bool) {
+        self.0
+            .write(gc_context)
+            .flags
+            .set(EditTextFlag::CONDENSE_WHITE, condense_white);
+    }
+
     pub fn is_selectable(self) -> bool {
         !self.0.read().flags.contains(EditTextFlag::NO_SELECT)
     }
@@ -734,6 +857,8 @@ impl<'gc> EditText<'gc> {
         edit_text.scroll = 1;
 
         if autosize != AutoSizeMode::None {
+            // Word wrapped text was already laid out to fit the current width, so only the
+            // height grows. Otherwise, the width grows to fit the text as well.
             if !is_word_wrap {
                 // The edit text's bounds needs to have the padding baked in.
                 let width = intrinsic_bounds.width() + padding;
@@ -747,9 +872,6 @@ impl<'gc> EditText<'gc> {
                 };
                 edit_text.bounds.x_min = new_x;
                 edit_text.bounds.set_width(width);
-            } else {
-                let width = edit_text.static_data.bounds.width();
-                edit_text.bounds.set_width(width);
             }
             let height = intrinsic_bounds.height() + padding;
             edit_text.bounds.set_height(height);
@@ -887,16 +1009,22 @@ impl<'gc> EditText<'gc> {
         // We're cheating a bit and not actually rendering text using the OS/web.
         // Instead, we embed an SWF version of Noto Sans to use as the "device font", and render
         // it the same as any other SWF outline text.
-        if let Some((text, _tf, font, params, color)) =
+        if let Some((text, tf, font, params, color)) =
             lbox.as_renderable_text(edit_text.text_spans.displayed_text())
         {
             let baseline_adjustment =
                 font.get_baseline_for_height(params.height()) - params.height();
+            let is_faux_italic = is_faux_italic(tf, font);
             font.evaluate(
                 text,
                 self.text_transform(color, baseline_adjustment),
                 params,
                 |pos, transform, glyph: &Glyph, advance, x| {
+                    let mut transform = transform.clone();
+                    if is_faux_italic {
+                        transform.matrix = synthesize_italic(transform.matrix);
+                    }
+
                     // If it's highlighted, override the color.
                     match visible_selection {
                         Some(visible_selection) if visible_selection.contains(start + pos) => {
@@ -918,7 +1046,7 @@ impl<'gc> EditText<'gc> {
                             });
                         }
                         _ => {
-                            context.transform_stack.push(transform);
+                            context.transform_stack.push(&transform);
                         }
                     }
 
@@ -1155,15 +1283,40 @@ impl<'gc> EditText<'gc> {
         self.0.write(context.gc_context).max_chars = value;
     }
 
-    pub fn screen_position_to_index(self, position: Point<Twips>) -> Option<usize> {
+    pub fn restrict(self) -> Option<WString> {
+        self.0
+            .read()
+            .restrict
+            .as_ref()
+            .map(|restrict| restrict.value().into())
+    }
+
+    pub fn set_restrict(self, value: Option<&WStr>, context: &mut UpdateContext<'_, 'gc>) {
+        self.0.write(context.gc_context).restrict = value.map(EditTextRestrict::new);
+    }
+
+    /// Map a character typed by the user to the one that gets entered, or
+    /// `None` if `restrict` doesn't allow it.
+    fn restricted_char(self, character: char) -> Option<char> {
+        match &self.0.read().restrict {
+            Some(restrict) => restrict.to_allowed(character),
+            None => Some(character),
+        }
+    }
+
+    /// Find the character under a position in this text field's local space.
+    ///
+    /// Returns the index of that character in the text, and whether the position lies on its
+    /// right half. Positions which aren't over a glyph, such as past the end of a line or below
+    /// the last line, don't resolve to any character.
+    fn char_at_local_position(self, position: Point<Twips>) -> Option<(usize, bool)> {
         let text = self.0.read();
-        let Some(mut position) = self.global_to_local(position) else {
-            return None;
-        };
-        position.x += Twips::from_pixels(Self::INTERNAL_PADDING);
-        position.y += Twips::from_pixels(Self::INTERNAL_PADDING);
+        let position = text.local_to_layout(position);
 
         for layout_box in text.layout.iter() {
+            let LayoutContent::Text { start, .. } = layout_box.content() else {
+                continue;
+            };
             let origin = layout_box.bounds().origin();
             let mut matrix = Matrix::translate(origin.x(), origin.y());
             matrix = matrix.inverse().expect("Invertible layout matrix");
@@ -1185,11 +1338,7 @@ impl<'gc> EditText<'gc> {
                             && local_position.y >= Twips::ZERO
                             && local_position.y <= params.height()
                         {
-                            if local_position.x >= x + (advance / 2) {
-                                result = Some(string_utils::next_char_boundary(text, pos));
-                            } else {
-                                result = Some(pos);
-                            }
+                            result = Some((start + pos, local_position.x >= x + (advance / 2)));
                         }
                     },
                 );
@@ -1202,6 +1351,173 @@ impl<'gc> EditText<'gc> {
         None
     }
 
+    /// Find the caret position closest to a given screen position.
+    pub fn screen_position_to_index(self, position: Point<Twips>) -> Option<usize> {
+        let position = self.global_to_local(position)?;
+        let (index, is_right_half) = self.char_at_local_position(position)?;
+        if is_right_half {
+            Some(string_utils::next_char_boundary(&self.text(), index))
+        } else {
+            Some(index)
+        }
+    }
+
+    /// Extend the selection that is being dragged out with the mouse to a screen position.
+    ///
+    /// Positions above or below the field extend the selection to the start of the first
+    /// visible line, or the end of the last visible line. Other positions which aren't over
+    /// any character leave the selection unchanged.
+    pub fn drag_selection(self, context: &mut UpdateContext<'_, 'gc>, position: Point<Twips>) {
+        let Some(selection) = self.selection().filter(|_| self.is_selectable()) else {
+            return;
+        };
+        let Some(local_position) = self.global_to_local(position) else {
+            return;
+        };
+
+        let bounds = self.0.read().bounds.clone();
+        let index = if local_position.y < bounds.y_min {
+            self.line_ranges()
+                .get(self.scroll() - 1)
+                .map(|line| line.start)
+        } else if local_position.y > bounds.y_max {
+            self.line_ranges()
+                .get(self.bottom_scroll() - 1)
+                .map(|line| line.end)
+        } else {
+            self.screen_position_to_index(position)
+        };
+
+        if let Some(index) = index {
+            self.set_selection(
+                Some(TextSelection::for_range(selection.from(), index)),
+                context.gc_context,
+            );
+        }
+    }
+
+    /// Scroll towards a screen position outside of the field while a selection is being
+    /// dragged out with the mouse, then extend the selection to it.
+    ///
+    /// This is called once per frame, so holding the mouse still outside of the field keeps
+    /// scrolling it, one line at a time.
+    pub fn auto_scroll_selection(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        position: Point<Twips>,
+    ) {
+        if self.selection().is_none() || !self.is_selectable() {
+            return;
+        }
+        let Some(local_position) = self.global_to_local(position) else {
+            return;
+        };
+
+        let bounds = self.0.read().bounds.clone();
+        if local_position.y < bounds.y_min {
+            self.set_scroll(self.scroll() as f64 - 1.0, context);
+        } else if local_position.y > bounds.y_max {
+            self.set_scroll(self.scroll() as f64 + 1.0, context);
+        }
+
+        if !self.is_word_wrap() {
+            let hscroll = self.hscroll();
+            if local_position.x < bounds.x_min {
+                self.set_hscroll((hscroll - Self::DRAG_HSCROLL_STEP).max(0.0), context);
+            } else if local_position.x > bounds.x_max {
+                let hscroll = (hscroll + Self::DRAG_HSCROLL_STEP).min(self.maxhscroll());
+                self.set_hscroll(hscroll, context);
+            }
+        }
+
+        self.drag_selection(context, position);
+    }
+
+    /// Find the index of the character under a position in this text field's local space.
+    ///
+    /// Unlike `screen_position_to_index`, this never snaps to a nearby character.
+    pub fn char_index_at_point(self, position: Point<Twips>) -> Option<usize> {
+        self.char_at_local_position(position)
+            .map(|(index, _is_right_half)| index)
+    }
+
+    /// Find the hyperlink under a given screen position, if any.
+    ///
+    /// Returns the `url` and `target` of the text run at that position, which
+    /// may have come from an `<a>` tag or from a `TextFormat` set by script.
+    pub fn link_at_position(self, position: Point<Twips>) -> Option<(WString, WString)> {
+        let text = self.0.read();
+        let position = text.local_to_layout(self.global_to_local(position)?);
+
+        for layout_box in text.layout.iter() {
+            if !layout_box.is_text_box() {
+                continue;
+            }
+
+            let bounds = layout_box.bounds();
+            if position.x < bounds.offset_x()
+                || position.x >= bounds.extent_x()
+                || position.y < bounds.offset_y()
+                || position.y >= bounds.extent_y()
+            {
+                continue;
+            }
+
+            if let Some((_text, tf, _font, _params, _color)) =
+                layout_box.as_renderable_text(text.text_spans.text())
+            {
+                return match &tf.url {
+                    Some(url) if !url.is_empty() => {
+                        Some((url.clone(), tf.target.clone().unwrap_or_default()))
+                    }
+                    _ => None,
+                };
+            }
+        }
+
+        None
+    }
+
+    /// Activate a hyperlink that was clicked in this text field.
+    ///
+    /// `event:` links dispatch a `TextEvent.LINK` in AVM2, while everything
+    /// else is handed to the navigator.
+    fn follow_link(self, context: &mut UpdateContext<'_, 'gc>, url: &WStr, target: &WStr) {
+        const EVENT_PREFIX: &[u8] = b"event:";
+        const ASFUNCTION_PREFIX: &[u8] = b"asfunction:";
+
+        let prefix_len = EVENT_PREFIX.len();
+        let is_event = url.len() >= prefix_len
+            && url[..prefix_len].eq_ignore_case(WStr::from_units(EVENT_PREFIX));
+        if is_event {
+            if let Avm2Value::Object(object) = self.object2() {
+                let mut activation = Avm2Activation::from_nothing(context.reborrow());
+                let text = AvmString::new(activation.context.gc_context, &url[prefix_len..]);
+                let link_evt = Avm2EventObject::text_event(
+                    &mut activation,
+                    "link",
+                    text,
+                    true,  /* bubbles */
+                    false, /* cancelable */
+                );
+                Avm2::dispatch_event(&mut activation.context, link_evt, object);
+            }
+            return;
+        }
+
+        let prefix_len = ASFUNCTION_PREFIX.len();
+        if url.len() >= prefix_len
+            && url[..prefix_len].eq_ignore_case(WStr::from_units(ASFUNCTION_PREFIX))
+        {
+            tracing::warn!("EditText: asfunction links are not yet supported: {}", url);
+            return;
+        }
+
+        context
+            .navigator
+            .navigate_to_url(&url.to_utf8_lossy(), &target.to_utf8_lossy(), None);
+    }
+
     /// The number of characters that currently can be inserted, considering `TextField.maxChars`
     /// constraint, current text length, and current text selection length.
     fn available_chars(self) -> usize {
@@ -1220,6 +1536,117 @@ impl<'gc> EditText<'gc> {
         }
     }
 
+    /// Compute the range of text covered by each laid out line, in order.
+    fn line_ranges(self) -> Vec<Range<usize>> {
+        let read = self.0.read();
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut line_extent = None;
+
+        for layout_box in read.layout.iter() {
+            let LayoutContent::Text { start, end, .. } = *layout_box.content() else {
+                continue;
+            };
+            let bounds = layout_box.bounds();
+
+            // As in `get_line_data`, a box that starts below the current line begins a new one.
+            match (ranges.last_mut(), line_extent) {
+                (Some(range), Some(extent)) if bounds.offset_y() <= extent => {
+                    range.start = range.start.min(start);
+                    range.end = range.end.max(end);
+                    line_extent = Some(extent.max(bounds.extent_y()));
+                }
+                _ => {
+                    ranges.push(start..end);
+                    line_extent = Some(bounds.extent_y());
+                }
+            }
+        }
+
+        ranges
+    }
+
+    /// Find where the caret ends up after a keyboard navigation from `position`.
+    fn navigate(self, control_code: TextControlCode, position: usize) -> usize {
+        let text = self.text();
+        let lines = self.line_ranges();
+        let line_index = lines.iter().rposition(|line| line.start <= position);
+        let line = line_index
+            .map(|i| lines[i].clone())
+            .unwrap_or(0..text.len());
+
+        match control_code {
+            TextControlCode::MoveLeftWord | TextControlCode::SelectLeftWord => {
+                string_utils::prev_word_boundary(&text, position)
+            }
+            TextControlCode::MoveRightWord | TextControlCode::SelectRightWord => {
+                string_utils::next_word_boundary(&text, position)
+            }
+            TextControlCode::MoveLineStart | TextControlCode::SelectLineStart => line.start,
+            TextControlCode::MoveLineEnd | TextControlCode::SelectLineEnd => {
+                // A wrapped line ends where the next one starts, which would put the caret
+                // on the next line. Stop before the character the line wrapped at instead.
+                let is_wrapped = line_index
+                    .and_then(|i| lines.get(i + 1))
+                    .map_or(false, |next| next.start <= line.end);
+                if is_wrapped && line.end > line.start {
+                    string_utils::prev_char_boundary(&text, line.end)
+                } else {
+                    line.end
+                }
+            }
+            TextControlCode::MoveUp
+            | TextControlCode::SelectUp
+            | TextControlCode::MoveDown
+            | TextControlCode::SelectDown
+            | TextControlCode::MovePageUp
+            | TextControlCode::SelectPageUp
+            | TextControlCode::MovePageDown
+            | TextControlCode::SelectPageDown => {
+                let is_down = matches!(
+                    control_code,
+                    TextControlCode::MoveDown
+                        | TextControlCode::SelectDown
+                        | TextControlCode::MovePageDown
+                        | TextControlCode::SelectPageDown
+                );
+                let is_page = matches!(
+                    control_code,
+                    TextControlCode::MovePageUp
+                        | TextControlCode::SelectPageUp
+                        | TextControlCode::MovePageDown
+                        | TextControlCode::SelectPageDown
+                );
+                // A page is as many lines as are currently visible.
+                let distance = if is_page {
+                    self.bottom_scroll().saturating_sub(self.scroll()) + 1
+                } else {
+                    1
+                };
+                let target = line_index.and_then(|i| {
+                    let target = if is_down {
+                        Some(i + distance).filter(|&i| i < lines.len())
+                    } else {
+                        i.checked_sub(distance)
+                    };
+                    // Paging past the first or last line stops there.
+                    match target {
+                        None if is_page && is_down => lines.last(),
+                        None if is_page => lines.first(),
+                        target => target.and_then(|i| lines.get(i)),
+                    }
+                });
+
+                // Keep the same column, as far as the other line is long enough.
+                // Moving past the first or last line leaves the caret where it is.
+                match target {
+                    Some(target) => (target.start + (position - line.start)).min(target.end),
+                    None => position,
+                }
+            }
+            _ => position,
+        }
+    }
+
     pub fn text_control_input(
         self,
         control_code: TextControlCode,
@@ -1273,6 +1700,36 @@ impl<'gc> EditText<'gc> {
                         )
                     }
                 }
+                TextControlCode::MoveLeftWord
+                | TextControlCode::MoveRightWord
+                | TextControlCode::MoveLineStart
+                | TextControlCode::MoveLineEnd
+                | TextControlCode::MoveUp
+                | TextControlCode::MoveDown
+                | TextControlCode::MovePageUp
+                | TextControlCode::MovePageDown => {
+                    let new_pos = self.navigate(control_code, selection.to);
+                    self.set_selection(
+                        Some(TextSelection::for_position(new_pos)),
+                        context.gc_context,
+                    );
+                }
+                TextControlCode::SelectLeftWord
+                | TextControlCode::SelectRightWord
+                | TextControlCode::SelectLineStart
+                | TextControlCode::SelectLineEnd
+                | TextControlCode::SelectUp
+                | TextControlCode::SelectDown
+                | TextControlCode::SelectPageUp
+                | TextControlCode::SelectPageDown => {
+                    if is_selectable {
+                        let new_pos = self.navigate(control_code, selection.to);
+                        self.set_selection(
+                            Some(TextSelection::for_range(selection.from, new_pos)),
+                            context.gc_context,
+                        );
+                    }
+                }
                 TextControlCode::SelectAll => {
                     if is_selectable {
                         self.set_selection(
@@ -1282,22 +1739,48 @@ impl<'gc> EditText<'gc> {
                     }
                 }
                 TextControlCode::Copy => {
-                    if !selection.is_caret() {
+                    // Masked or unselectable text can't be copied.
+                    if is_selectable && !self.is_password() && !selection.is_caret() {
                         let text = &self.text()[selection.start()..selection.end()];
                         context.ui.set_clipboard_content(text.to_string());
                     }
                 }
                 TextControlCode::Paste => {
-                    let text = &context.ui.clipboard_content();
-                    // TODO: To match Flash Player, we should truncate pasted text that is longer than max_chars
-                    // instead of canceling the paste action entirely
-                    if text.len() <= self.available_chars() {
-                        self.replace_text(
-                            selection.start(),
-                            selection.end(),
-                            &WString::from_utf8(text),
-                            context,
-                        );
+                    let mut text = WString::from_utf8(&context.ui.clipboard_content());
+                    if !self.is_multiline() {
+                        // Line breaks can only be entered into multiline fields
+                        text = text
+                            .iter()
+                            .filter(|&c| c != u16::from(b'\r') && c != u16::from(b'\n'))
+                            .collect();
+                    }
+                    if self.0.read().restrict.is_some() {
+                        text = text
+                            .chars()
+                            .filter_map(|c| {
+                                self.restricted_char(c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                            })
+                            .fold(WString::new(), |mut text, c| {
+                                text.push_char(c);
+                                text
+                            });
+                    }
+                    // Like Flash Player, paste as much of the text as `maxChars` allows,
+                    // without splitting a surrogate pair.
+                    let available_chars = self.available_chars();
+                    if text.len() > available_chars {
+                        let mut end = 0;
+                        loop {
+                            let next = string_utils::next_char_boundary(&text, end);
+                            if next == end || next > available_chars {
+                                break;
+                            }
+                            end = next;
+                        }
+                        text = text[..end].into();
+                    }
+                    if !text.is_empty() || !selection.is_caret() {
+                        self.replace_text(selection.start(), selection.end(), &text, context);
                         let new_pos = selection.start() + text.len();
                         if is_selectable {
                             self.set_selection(
@@ -1314,7 +1797,8 @@ impl<'gc> EditText<'gc> {
                     }
                 }
                 TextControlCode::Cut => {
-                    if !selection.is_caret() {
+                    // Like copying, masked or unselectable text can't be cut.
+                    if is_selectable && !self.is_password() && !selection.is_caret() {
                         let text = &self.text()[selection.start()..selection.end()];
                         context.ui.set_clipboard_content(text.to_string());
 
@@ -1324,17 +1808,10 @@ impl<'gc> EditText<'gc> {
                             WStr::empty(),
                             context,
                         );
-                        if is_selectable {
-                            self.set_selection(
-                                Some(TextSelection::for_position(selection.start())),
-                                context.gc_context,
-                            );
-                        } else {
-                            self.set_selection(
-                                Some(TextSelection::for_position(self.text().len())),
-                                context.gc_context,
-                            );
-                        }
+                        self.set_selection(
+                            Some(TextSelection::for_position(selection.start())),
+                            context.gc_context,
+                        );
                         changed = true;
                     }
                 }
@@ -1374,27 +1851,69 @@ impl<'gc> EditText<'gc> {
                 }
                 _ => {}
             }
-            if changed {
+            let scrolled = self.scroll_to_caret(context);
+            if changed || scrolled {
                 let mut activation = Avm1Activation::from_nothing(
                     context.reborrow(),
                     ActivationIdentifier::root("[Propagate Text Binding]"),
                     self.into(),
                 );
-                self.propagate_text_binding(&mut activation);
-                self.on_changed(&mut activation);
+                if changed {
+                    self.propagate_text_binding(&mut activation);
+                    self.on_changed(&mut activation);
+                }
+                if scrolled {
+                    self.on_scroller(&mut activation);
+                }
             }
         }
     }
 
+    /// Scroll vertically, as little as possible, so that the line holding the caret is visible.
+    ///
+    /// Returns whether `scroll` changed.
+    fn scroll_to_caret(self, context: &mut UpdateContext<'_, 'gc>) -> bool {
+        let Some(selection) = self.selection() else {
+            return false;
+        };
+        let Some(line_index) = self
+            .line_ranges()
+            .iter()
+            .rposition(|line| line.start <= selection.to)
+        else {
+            return false;
+        };
+
+        // Like `scroll` and `bottom_scroll`, this is 1-based.
+        let line = line_index + 1;
+        let scroll = self.scroll();
+        let bottom_scroll = self.bottom_scroll();
+        let new_scroll = if line < scroll {
+            line
+        } else if line > bottom_scroll {
+            scroll + (line - bottom_scroll)
+        } else {
+            return false;
+        };
+
+        // `set_scroll` keeps this within `maxscroll`.
+        self.set_scroll(new_scroll as f64, context);
+        self.scroll() != scroll
+    }
+
     pub fn text_input(self, character: char, context: &mut UpdateContext<'_, 'gc>) {
         if self.0.read().flags.contains(EditTextFlag::READ_ONLY) {
             return;
         }
 
+        let Some(character) = self.restricted_char(character) else {
+            return;
+        };
+
         if let Some(selection) = self.selection() {
             let mut changed = false;
             match character as u8 {
-                code if !(code as char).is_control() => {
+                code if !(code as char).is_control() || character == '\t' => {
                     if self.available_chars() > 0 {
                         self.replace_text(
                             selection.start(),
@@ -1414,6 +1933,7 @@ impl<'gc> EditText<'gc> {
             }
 
             if changed {
+                let scrolled = self.scroll_to_caret(context);
                 let mut activation = Avm1Activation::from_nothing(
                     context.reborrow(),
                     ActivationIdentifier::root("[Propagate Text Binding]"),
@@ -1421,6 +1941,9 @@ impl<'gc> EditText<'gc> {
                 );
                 self.propagate_text_binding(&mut activation);
                 self.on_changed(&mut activation);
+                if scrolled {
+                    self.on_scroller(&mut activation);
+                }
             }
         }
     }
@@ -1465,6 +1988,22 @@ impl<'gc> EditText<'gc> {
         }
     }
 
+    /// Notify listeners that the user scrolled this text field, by broadcasting
+    /// `onScroller` in AVM1 or dispatching `Event.SCROLL` in AVM2.
+    fn on_scroller(&self, activation: &mut Avm1Activation<'_, 'gc>) {
+        if let Avm1Value::Object(object) = self.object() {
+            let _ = object.call_method(
+                "broadcastMessage".into(),
+                &["onScroller".into(), object.into()],
+                activation,
+                ExecutionReason::Special,
+            );
+        } else if let Avm2Value::Object(object) = self.object2() {
+            let scroll_evt = Avm2EventObject::bare_default_event(&mut activation.context, "scroll");
+            Avm2::dispatch_event(&mut activation.context, scroll_evt, object);
+        }
+    }
+
     /// Construct the text field's AVM1 representation.
     fn construct_as_avm1_object(&self, context: &mut UpdateContext<'_, 'gc>, run_frame: bool) {
         let mut text = self.0.write(context.gc_context);
@@ -1781,17 +2320,7 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         );
         context.commands.activate_mask();
 
-        let scroll_offset = if edit_text.scroll > 1 {
-            let line_data = &edit_text.line_data;
-
-            if let Some(line_data) = line_data.get(edit_text.scroll - 1) {
-                line_data.offset
-            } else {
-                Twips::ZERO
-            }
-        } else {
-            Twips::ZERO
-        };
+        let scroll_offset = edit_text.scroll_offset();
         // TODO: Where does this come from? How is this different than INTERNAL_PADDING? Does this apply to y as well?
         // If this is actually right, offset the border in `redraw_border` instead of doing an extra push.
         context.transform_stack.push(&Transform {
@@ -1914,12 +2443,16 @@ impl<'gc> TInteractiveObject<'gc> for EditText<'gc> {
         self.into()
     }
 
+    fn tab_enabled_default(self, _context: &mut UpdateContext<'_, 'gc>) -> bool {
+        self.is_editable()
+    }
+
     fn filter_clip_event(
         self,
         _context: &mut UpdateContext<'_, 'gc>,
         event: ClipEvent,
     ) -> ClipEventResult {
-        if event != ClipEvent::Press {
+        if event != ClipEvent::Press && event != ClipEvent::Release {
             return ClipEventResult::NotHandled;
         }
 
@@ -1929,14 +2462,24 @@ impl<'gc> TInteractiveObject<'gc> for EditText<'gc> {
     fn event_dispatch(
         self,
         context: &mut UpdateContext<'_, 'gc>,
-        _event: ClipEvent<'gc>,
+        event: ClipEvent<'gc>,
     ) -> ClipEventResult {
+        if event == ClipEvent::Release {
+            if let Some((url, target)) = self.link_at_position(*context.mouse_position) {
+                self.follow_link(context, &url, &target);
+            }
+            return ClipEventResult::Handled;
+        }
+
         if self.is_editable() || self.is_selectable() {
             let tracker = context.focus_tracker;
             tracker.set(Some(self.into()), context);
         }
+        // The mouse can't move the caret around in a field that isn't selectable,
+        // even if it's editable; typing then always continues at the end of the text.
         if let Some(position) = self
             .screen_position_to_index(*context.mouse_position)
+            .filter(|_| self.is_selectable())
             .map(TextSelection::for_position)
         {
             self.0.write(context.gc_context).selection = Some(position);
@@ -1957,7 +2500,9 @@ impl<'gc> TInteractiveObject<'gc> for EditText<'gc> {
         // The text is hovered if the mouse is over any child nodes.
         if self.visible()
             && self.mouse_enabled()
-            && self.is_selectable()
+            && (self.is_selectable()
+                || self.is_editable()
+                || self.link_at_position(point).is_some())
             && self.hit_test_shape(context, point, HitTestOptions::MOUSE_PICK)
         {
             Some((*self).into())
@@ -1988,8 +2533,10 @@ impl<'gc> TInteractiveObject<'gc> for EditText<'gc> {
         }
     }
 
-    fn mouse_cursor(self, _context: &mut UpdateContext<'_, 'gc>) -> MouseCursor {
-        if self.is_selectable() {
+    fn mouse_cursor(self, context: &mut UpdateContext<'_, 'gc>) -> MouseCursor {
+        if self.link_at_position(*context.mouse_position).is_some() {
+            MouseCursor::Hand
+        } else if self.is_selectable() || self.is_editable() {
             MouseCursor::IBeam
         } else {
             MouseCursor::Arrow
@@ -2015,6 +2562,7 @@ bitflags::bitflags! {
         const WAS_STATIC = 1 << 10;
         const BORDER = 1 << 11;
         const NO_SELECT = 1 << 12;
+        const CONDENSE_WHITE = 1 << 13;
         const SWF_FLAGS = Self::READ_ONLY.bits() | Self::PASSWORD.bits() | Self::MULTILINE.bits() | Self::WORD_WRAP.bits() | Self::USE_OUTLINES.bits() |
                           Self::HTML.bits() | Self::WAS_STATIC.bits() | Self::BORDER.bits() | Self::NO_SELECT.bits();
     }
@@ -2111,3 +2659,222 @@ impl TextSelection {
         self.to == self.from
     }
 }
+
+/// The characters that a user is allowed to enter into a text field, parsed
+/// from its `restrict` property.
+///
+/// The string lists characters and ranges of characters (such as `A-Z`) to
+/// allow. Each `^` switches between allowing and denying the characters that
+/// follow it; if the string starts with `^`, every character that isn't
+/// denied is allowed. A `\` escapes the next character, so that `-`, `^` and
+/// `\` can be listed themselves.
+#[derive(Clone, Debug, Collect)]
+#[collect(require_static)]
+pub struct EditTextRestrict {
+    /// The original `restrict` string.
+    value: WString,
+
+    /// Whether characters that aren't listed at all are allowed.
+    allow_by_default: bool,
+
+    /// The listed ranges of characters, and whether they are allowed.
+    /// Later ranges take precedence over earlier ones.
+    ranges: Vec<(RangeInclusive<char>, bool)>,
+}
+
+impl EditTextRestrict {
+    pub fn new(value: &WStr) -> Self {
+        let mut chars = value
+            .chars()
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .peekable();
+        let mut allow = true;
+        let mut ranges = Vec::new();
+
+        while let Some(c) = chars.next() {
+            let start = match c {
+                '^' => {
+                    allow = !allow;
+                    continue;
+                }
+                '\\' => match chars.next() {
+                    Some(c) => c,
+                    None => break,
+                },
+                c => c,
+            };
+
+            let mut end = start;
+            if chars.peek() == Some(&'-') {
+                chars.next();
+                match chars.next() {
+                    Some('\\') => end = chars.next().unwrap_or(start),
+                    Some(c) => end = c,
+                    // A `-` at the very end is listed as itself.
+                    None => ranges.push(('-'..='-', allow)),
+                }
+            }
+            ranges.push((start..=end, allow));
+        }
+
+        Self {
+            value: value.into(),
+            allow_by_default: value.starts_with(b'^'),
+            ranges,
+        }
+    }
+
+    pub fn value(&self) -> &WStr {
+        &self.value
+    }
+
+    pub fn is_allowed(&self, character: char) -> bool {
+        self.ranges
+            .iter()
+            .rev()
+            .find(|(range, _)| range.contains(&character))
+            .map_or(self.allow_by_default, |(_, allow)| *allow)
+    }
+
+    /// Map a typed character to the one that gets entered, if any.
+    ///
+    /// Like Flash Player, a character that isn't allowed is entered with its
+    /// case swapped if that is allowed, so that `A-Z` turns typed lowercase
+    /// letters into uppercase ones.
+    pub fn to_allowed(&self, character: char) -> Option<char> {
+        if self.is_allowed(character) {
+            return Some(character);
+        }
+
+        let unit = u16::try_from(u32::from(character)).ok()?;
+        let lowercase = string_utils::swf_to_lowercase(unit);
+        let swapped = if lowercase != unit {
+            lowercase
+        } else {
+            string_utils::swf_to_uppercase(unit)
+        };
+        char::from_u32(swapped.into()).filter(|c| self.is_allowed(*c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_faux_italic, synthesize_italic, EditTextRestrict};
+    use crate::font::Font;
+    use crate::html::TextFormat;
+    use crate::prelude::*;
+    use crate::string::WStr;
+    use gc_arena::rootless_arena;
+    use ruffle_render::backend::{null::NullRenderer, ViewportDimensions};
+
+    fn restrict(value: &str) -> EditTextRestrict {
+        EditTextRestrict::new(WStr::from_units(value.as_bytes()))
+    }
+
+    fn allowed(restrict: &EditTextRestrict, chars: &str) -> String {
+        chars.chars().filter(|c| restrict.is_allowed(*c)).collect()
+    }
+
+    const SAMPLE: &str = "aAzZ059-^\\ ";
+
+    #[test]
+    fn restrict_characters_and_ranges() {
+        assert_eq!(allowed(&restrict("a-z"), SAMPLE), "az");
+        assert_eq!(allowed(&restrict("A-Z 0-9"), SAMPLE), "AZ059 ");
+        assert_eq!(allowed(&restrict("aZ"), SAMPLE), "aZ");
+        assert_eq!(allowed(&restrict(""), SAMPLE), "");
+    }
+
+    #[test]
+    fn restrict_deny_sections() {
+        assert_eq!(allowed(&restrict("0-9^5"), SAMPLE), "09");
+        assert_eq!(allowed(&restrict("^0-9"), SAMPLE), "aAzZ-^\\ ");
+        assert_eq!(allowed(&restrict("^a-z^m"), "amz"), "m");
+        assert_eq!(allowed(&restrict("a-z^m-z^z"), "amyz"), "az");
+    }
+
+    #[test]
+    fn restrict_escapes() {
+        assert_eq!(allowed(&restrict("\\-"), SAMPLE), "-");
+        assert_eq!(allowed(&restrict("\\^"), SAMPLE), "^");
+        assert_eq!(allowed(&restrict("\\\\"), SAMPLE), "\\");
+        assert_eq!(allowed(&restrict("5-"), SAMPLE), "5-");
+        assert_eq!(allowed(&restrict("^\\^"), "a^"), "a");
+    }
+
+    #[test]
+    fn restrict_unicode() {
+        let restrict = EditTextRestrict::new(WStr::from_units(&[0x3B1, u16::from(b'-'), 0x3C9]));
+        assert_eq!(allowed(&restrict, "aαλω"), "αλω");
+    }
+
+    #[test]
+    fn restrict_swaps_case() {
+        let restrict = restrict("A-Z");
+        assert_eq!(restrict.to_allowed('q'), Some('Q'));
+        assert_eq!(restrict.to_allowed('Q'), Some('Q'));
+        assert_eq!(restrict.to_allowed('1'), None);
+    }
+
+    #[test]
+    fn faux_italic_slants_glyphs_right() {
+        let matrix = synthesize_italic(Matrix::translate(Twips::new(100), Twips::ZERO));
+
+        // The baseline stays in place...
+        let on_baseline = matrix * Point::new(Twips::new(10), Twips::ZERO);
+        assert_eq!(on_baseline, Point::new(Twips::new(110), Twips::ZERO));
+
+        // ...while points above it lean to the right.
+        let above_baseline = matrix * Point::new(Twips::new(10), Twips::new(-100));
+        assert_eq!(
+            above_baseline,
+            Point::new(Twips::new(130), Twips::new(-100))
+        );
+    }
+
+    /// Load the built-in Noto Sans as if the movie had embedded it, optionally as an italic face.
+    fn with_embedded_font<F>(is_italic: bool, callback: F)
+    where
+        F: for<'gc> FnOnce(Font<'gc>),
+    {
+        rootless_arena(|mc| {
+            let mut renderer = NullRenderer::new(ViewportDimensions {
+                width: 0,
+                height: 0,
+                scale_factor: 1.0,
+            });
+            let mut reader =
+                swf::read::Reader::new(include_bytes!("../../assets/noto-sans-definefont3.bin"), 8);
+            let mut tag = reader
+                .read_define_font_2(3)
+                .expect("Built-in font should compile");
+            tag.flags.set(swf::FontFlag::IS_ITALIC, is_italic);
+            callback(Font::from_swf_tag(
+                mc,
+                &mut renderer,
+                tag,
+                reader.encoding(),
+            ));
+        })
+    }
+
+    #[test]
+    fn faux_italic_for_embedded_font_without_italic_face() {
+        let italic = TextFormat {
+            italic: Some(true),
+            ..Default::default()
+        };
+        let regular = TextFormat {
+            italic: Some(false),
+            ..Default::default()
+        };
+
+        with_embedded_font(false, |font| {
+            assert!(is_faux_italic(&italic, font));
+            assert!(!is_faux_italic(&regular, font));
+        });
+        with_embedded_font(true, |font| {
+            assert!(!is_faux_italic(&italic, font));
+        });
+    }
+}
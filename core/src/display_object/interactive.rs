@@ -83,6 +83,24 @@ pub struct InteractiveObjectBase<'gc> {
     flags: InteractiveObjectFlags,
     context_menu: Avm2Value<'gc>,
 
+    /// Whether this object can be focused using the Tab key.
+    ///
+    /// `None` means that the value hasn't been set, and depends on the type
+    /// of this object.
+    tab_enabled: Option<bool>,
+
+    /// The position of this object in the tab order.
+    ///
+    /// `None` means that no index was set, and the object is ordered
+    /// automatically.
+    tab_index: Option<i32>,
+
+    /// Whether a yellow rectangle should be drawn around this object when it
+    /// has keyboard focus.
+    ///
+    /// `None` means that the stage's `stageFocusRect` property decides.
+    focus_rect: Option<bool>,
+
     /// The time of the last click registered on this object.
     ///
     /// This should be cleared to `None` when the mouse leaves the current
@@ -97,6 +115,9 @@ impl<'gc> Default for InteractiveObjectBase<'gc> {
             base: Default::default(),
             flags: InteractiveObjectFlags::MOUSE_ENABLED,
             context_menu: Avm2Value::Null,
+            tab_enabled: None,
+            tab_index: None,
+            focus_rect: None,
             last_click: None,
         }
     }
@@ -162,6 +183,45 @@ pub trait TInteractiveObject<'gc>:
         self.raw_interactive_mut(mc).context_menu = value;
     }
 
+    /// Whether this object can be focused using the Tab key, if no value
+    /// was set explicitly.
+    fn tab_enabled_default(self, _context: &mut UpdateContext<'_, 'gc>) -> bool {
+        false
+    }
+
+    /// Check if this object can be focused using the Tab key.
+    fn tab_enabled(self, context: &mut UpdateContext<'_, 'gc>) -> bool {
+        let tab_enabled = self.raw_interactive().tab_enabled;
+        tab_enabled.unwrap_or_else(|| self.tab_enabled_default(context))
+    }
+
+    /// Set if this object can be focused using the Tab key.
+    fn set_tab_enabled(self, mc: MutationContext<'gc, '_>, value: bool) {
+        self.raw_interactive_mut(mc).tab_enabled = Some(value);
+    }
+
+    /// The explicitly set position of this object in the tab order, if any.
+    fn tab_index(self) -> Option<i32> {
+        self.raw_interactive().tab_index
+    }
+
+    /// Set the position of this object in the tab order, or `None` to order
+    /// it automatically.
+    fn set_tab_index(self, mc: MutationContext<'gc, '_>, value: Option<i32>) {
+        self.raw_interactive_mut(mc).tab_index = value;
+    }
+
+    /// The explicitly set `focusRect` value of this object, if any.
+    fn focus_rect(self) -> Option<bool> {
+        self.raw_interactive().focus_rect
+    }
+
+    /// Set whether a focus rectangle should be drawn around this object, or
+    /// `None` to defer to the stage.
+    fn set_focus_rect(self, mc: MutationContext<'gc, '_>, value: Option<bool>) {
+        self.raw_interactive_mut(mc).focus_rect = value;
+    }
+
     /// Filter the incoming clip event.
     ///
     /// If this returns `Handled`, then the rest of the event handling
@@ -2942,6 +2942,10 @@ impl<'gc> TInteractiveObject<'gc> for MovieClip<'gc> {
         self.into()
     }
 
+    fn tab_enabled_default(self, context: &mut UpdateContext<'_, 'gc>) -> bool {
+        self.is_button_mode(context)
+    }
+
     fn filter_clip_event(
         self,
         context: &mut UpdateContext<'_, 'gc>,
@@ -4266,34 +4270,7 @@ impl<'gc, 'a> MovieClip<'gc> {
             .library_for_movie_mut(self.movie())
             .get_sound(start_sound.id)
         {
-            use swf::SoundEvent;
-            // The sound event type is controlled by the "Sync" setting in the Flash IDE.
-            match start_sound.sound_info.event {
-                // "Event" sounds always play, independent of the timeline.
-                SoundEvent::Event => {
-                    let _ = context.start_sound(
-                        handle,
-                        &start_sound.sound_info,
-                        Some(self.into()),
-                        None,
-                    );
-                }
-
-                // "Start" sounds only play if an instance of the same sound is not already playing.
-                SoundEvent::Start => {
-                    if !context.is_sound_playing_with_handle(handle) {
-                        let _ = context.start_sound(
-                            handle,
-                            &start_sound.sound_info,
-                            Some(self.into()),
-                            None,
-                        );
-                    }
-                }
-
-                // "Stop" stops any active instances of a given sound.
-                SoundEvent::Stop => context.stop_sounds_with_handle(handle),
-            }
+            context.start_sound_with_sync(handle, &start_sound.sound_info, Some(self.into()));
         }
         Ok(())
     }
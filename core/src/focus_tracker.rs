@@ -1,22 +1,43 @@
-use crate::avm1::Avm1;
-use crate::avm1::Value;
-use crate::context::UpdateContext;
+use crate::avm1::{Activation, ActivationIdentifier, Avm1, TObject, Value};
+use crate::context::{RenderContext, UpdateContext};
 pub use crate::display_object::{
-    DisplayObject, TDisplayObject, TDisplayObjectContainer, TextSelection,
+    DisplayObject, TDisplayObject, TDisplayObjectContainer, TInteractiveObject, TextSelection,
 };
 use gc_arena::{Collect, GcCell, MutationContext};
+use ruffle_render::matrix::Matrix;
+use swf::{Color, Twips};
 
 #[derive(Clone, Copy, Collect)]
 #[collect(no_drop)]
-pub struct FocusTracker<'gc>(GcCell<'gc, Option<DisplayObject<'gc>>>);
+pub struct FocusTracker<'gc>(GcCell<'gc, FocusTrackerData<'gc>>);
+
+#[derive(Clone, Copy, Collect)]
+#[collect(no_drop)]
+struct FocusTrackerData<'gc> {
+    focus: Option<DisplayObject<'gc>>,
+
+    /// Whether the focus rectangle is shown around the focused object.
+    ///
+    /// This is only the case when the focus was moved with the keyboard.
+    highlight: bool,
+}
 
 impl<'gc> FocusTracker<'gc> {
+    /// The thickness of the focus rectangle, in pixels.
+    const HIGHLIGHT_THICKNESS: f64 = 2.0;
+
     pub fn new(gc_context: MutationContext<'gc, '_>) -> Self {
-        Self(GcCell::new(gc_context, None))
+        Self(GcCell::new(
+            gc_context,
+            FocusTrackerData {
+                focus: None,
+                highlight: false,
+            },
+        ))
     }
 
     pub fn get(&self) -> Option<DisplayObject<'gc>> {
-        *self.0.read()
+        self.0.read().focus
     }
 
     pub fn set(
@@ -24,7 +45,11 @@ impl<'gc> FocusTracker<'gc> {
         focused_element: Option<DisplayObject<'gc>>,
         context: &mut UpdateContext<'_, 'gc>,
     ) {
-        let old = std::mem::replace(&mut *self.0.write(context.gc_context), focused_element);
+        let old = {
+            let mut write = self.0.write(context.gc_context);
+            write.highlight = false;
+            std::mem::replace(&mut write.focus, focused_element)
+        };
 
         if old.is_none() && focused_element.is_none() {
             // We didn't have anything, we still don't, no change.
@@ -70,4 +95,155 @@ impl<'gc> FocusTracker<'gc> {
             }
         }
     }
+
+    /// Move the focus to the next object in the tab order, or the previous
+    /// one if `reverse` is set. The tab order wraps around at either end.
+    ///
+    /// The tab order consists of every visible, tab-enabled object on the
+    /// stage, in rendering order. If any of them has a `tabIndex`, only the
+    /// objects with one take part instead, sorted by it.
+    pub fn cycle(&self, context: &mut UpdateContext<'_, 'gc>, reverse: bool) {
+        let mut candidates = Vec::new();
+        Self::fill_tab_order(&mut candidates, context.stage.into(), context);
+        if candidates.iter().any(|(_, tab_index)| tab_index.is_some()) {
+            candidates.retain(|(_, tab_index)| tab_index.is_some());
+            // The sort is stable, so objects sharing an index stay in rendering order.
+            candidates.sort_by_key(|(_, tab_index)| *tab_index);
+        }
+        let tab_order: Vec<_> = candidates.into_iter().map(|(object, _)| object).collect();
+        if tab_order.is_empty() {
+            return;
+        }
+
+        let len = tab_order.len();
+        let current = self.get().and_then(|focused| {
+            tab_order
+                .iter()
+                .position(|o| DisplayObject::ptr_eq(*o, focused))
+        });
+        let next = match current {
+            Some(index) if reverse => (index + len - 1) % len,
+            Some(index) => (index + 1) % len,
+            None if reverse => len - 1,
+            None => 0,
+        };
+        let next = tab_order[next];
+        self.set(Some(next), context);
+        self.0.write(context.gc_context).highlight = true;
+
+        // Unlike focusing it from a script, tabbing into a text field selects all of its
+        // text in AVM2 as well.
+        if let Some(text_field) = next.as_edit_text() {
+            let length = text_field.text_length();
+            text_field.set_selection(
+                Some(TextSelection::for_range(0, length)),
+                context.gc_context,
+            );
+        }
+    }
+
+    /// Draw the yellow focus rectangle around the focused object, if the focus was moved there
+    /// with the keyboard.
+    ///
+    /// The object's `focusRect` decides whether the rectangle is drawn, falling back to
+    /// `Stage.stageFocusRect` when it's `null`. Text fields never get one, as they show
+    /// their caret instead.
+    pub fn render_highlight(&self, context: &mut RenderContext<'_, 'gc>) {
+        let data = *self.0.read();
+        let Some(focus) = data.focus.filter(|_| data.highlight) else {
+            return;
+        };
+        let Some(interactive) = focus.as_interactive() else {
+            return;
+        };
+        if focus.as_edit_text().is_some() || !focus.visible() {
+            return;
+        }
+        let enabled = interactive
+            .focus_rect()
+            .unwrap_or_else(|| context.stage.stage_focus_rect());
+        if !enabled {
+            return;
+        }
+
+        let world_matrix = context.stage.view_matrix() * *context.stage.base().matrix();
+        let bounds = world_matrix * focus.world_bounds();
+        let width = bounds.width().to_pixels() as f32;
+        let height = bounds.height().to_pixels() as f32;
+        let thickness = Self::HIGHLIGHT_THICKNESS as f32;
+        let x_min = bounds.x_min - Twips::from_pixels(Self::HIGHLIGHT_THICKNESS);
+        let y_min = bounds.y_min - Twips::from_pixels(Self::HIGHLIGHT_THICKNESS);
+        let color = Color::from_rgb(0xFFFF00, 255);
+        let mut draw_edge = |width, height, x, y| {
+            context
+                .commands
+                .draw_rect(color, Matrix::create_box(width, height, 0.0, x, y));
+        };
+
+        // The rectangle is drawn just outside of the object's bounds.
+        draw_edge(width + 2.0 * thickness, thickness, x_min, y_min);
+        draw_edge(width + 2.0 * thickness, thickness, x_min, bounds.y_max);
+        draw_edge(thickness, height, x_min, bounds.y_min);
+        draw_edge(thickness, height, bounds.x_max, bounds.y_min);
+    }
+
+    /// Collect every object that can be focused with the Tab key, along with its `tabIndex`.
+    fn fill_tab_order(
+        tab_order: &mut Vec<(DisplayObject<'gc>, Option<i32>)>,
+        object: DisplayObject<'gc>,
+        context: &mut UpdateContext<'_, 'gc>,
+    ) {
+        if !object.visible() {
+            return;
+        }
+
+        if let Some(interactive) = object.as_interactive() {
+            let tab_enabled = match Self::avm1_tab_property(object, "tabEnabled", context) {
+                Some(value) => value.as_bool(context.swf.version()),
+                None => interactive.tab_enabled(context),
+            };
+            if tab_enabled {
+                let tab_index = match Self::avm1_tab_property(object, "tabIndex", context) {
+                    Some(Value::Number(index)) if index >= 0.0 => Some(index as i32),
+                    Some(_) => None,
+                    None => interactive.tab_index(),
+                };
+                tab_order.push((object, tab_index));
+            }
+        }
+
+        if let Some(container) = object.as_container() {
+            let tab_children = match Self::avm1_tab_property(object, "tabChildren", context) {
+                Some(value) => value.as_bool(context.swf.version()),
+                None => container.raw_container().tab_children(),
+            };
+            if tab_children {
+                for child in container.iter_render_list() {
+                    Self::fill_tab_order(tab_order, child, context);
+                }
+            }
+        }
+    }
+
+    /// AVM1 has no built-in tab properties: `tabEnabled`, `tabIndex` and `tabChildren`
+    /// are read from ordinary properties, which are `undefined` until a script sets them.
+    ///
+    /// Returns `None` for AVM2 objects and for properties that aren't set.
+    fn avm1_tab_property(
+        object: DisplayObject<'gc>,
+        name: &'static str,
+        context: &mut UpdateContext<'_, 'gc>,
+    ) -> Option<Value<'gc>> {
+        let Value::Object(object) = object.object() else {
+            return None;
+        };
+        let mut activation = Activation::from_stub(
+            context.reborrow(),
+            ActivationIdentifier::root("[Tab Order]"),
+        );
+        match object.get(name, &mut activation) {
+            Ok(Value::Undefined) | Err(_) => None,
+            Ok(value) => Some(value),
+        }
+    }
 }
@@ -328,9 +328,10 @@ impl<'gc> Font<'gc> {
 
     /// Given a line of text, find the first breakpoint within the text.
     ///
-    /// This function assumes only `" "` is valid whitespace to split words on,
-    /// and will not attempt to break words that are longer than `width`, nor
-    /// will it break at newlines.
+    /// Words are split as described by `wrap_words`: at spaces and after
+    /// hyphens, but never right before a period or a comma. This function
+    /// will not attempt to break words that are longer than `width`, nor will
+    /// it break at newlines.
     ///
     /// The given `offset` determines the start of the initial line, while the
     /// `width` indicates how long the line is supposed to be. Be careful to
@@ -357,13 +358,11 @@ impl<'gc> Font<'gc> {
 
         let mut line_end = 0;
 
-        for word in text.split(b' ') {
-            let word_start = word.offset_in(text).unwrap();
-            let word_end = word_start + word.len();
-
+        for (word_start, word_end) in wrap_words(text) {
             let measure = self.measure(
-                // +1 is fine because ' ' is 1 unit
-                text.slice(word_start..word_end + 1).unwrap_or(word),
+                // +1 is fine because both ' ' and '-' are 1 unit
+                text.slice(word_start..word_end + 1)
+                    .unwrap_or(&text[word_start..word_end]),
                 params,
                 false,
             );
@@ -418,6 +417,45 @@ impl<'gc> Font<'gc> {
     }
 }
 
+/// Split a line of text into the words it may be wrapped between.
+///
+/// Each word is returned as the range from its start up to the character the
+/// line would break at, which is either a space or a hyphen. Like the space,
+/// the hyphen stays on the line it ends, so `well-known` may wrap as `well-`
+/// and `known`. A leading hyphen, such as in `-5`, is not a break opportunity.
+///
+/// There is never a break right before a period or a comma, so that such
+/// punctuation isn't left at the start of a line on its own.
+fn wrap_words(text: &WStr) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let is_break = move |i: usize| {
+        let follows_word = i > 0 && text.get(i - 1).map_or(false, |c| c != u16::from(b' '));
+        let is_break_char = match text.get(i) {
+            Some(c) if c == u16::from(b' ') => true,
+            Some(c) if c == u16::from(b'-') => follows_word,
+            _ => false,
+        };
+        let precedes_punctuation = matches!(
+            text.get(i + 1),
+            Some(c) if c == u16::from(b'.') || c == u16::from(b',')
+        );
+        is_break_char && !precedes_punctuation
+    };
+
+    let mut word_start = 0;
+    std::iter::from_fn(move || {
+        if word_start > text.len() {
+            return None;
+        }
+
+        let word_end = (word_start..text.len())
+            .find(|&i| is_break(i))
+            .unwrap_or(text.len());
+        let word = (word_start, word_end);
+        word_start = word_end + 1;
+        Some(word)
+    })
+}
+
 #[derive(Debug, Clone)]
 pub struct Glyph {
     // Handle to registered shape.
@@ -687,7 +725,8 @@ impl Default for TextRenderSettings {
 
 #[cfg(test)]
 mod tests {
-    use crate::font::{EvalParameters, Font};
+    use crate::font::{wrap_words, EvalParameters, Font};
+    use crate::html::{FormatSpans, TextFormat};
     use crate::player::Player;
     use crate::string::WStr;
     use gc_arena::{rootless_arena, MutationContext};
@@ -867,4 +906,74 @@ mod tests {
             assert_eq!(None, breakpoint5);
         });
     }
+
+    #[test]
+    fn wrap_words_splits_at_spaces_and_hyphens() {
+        let words = |text: &[u8]| wrap_words(WStr::from_units(text)).collect::<Vec<_>>();
+
+        assert_eq!(words(b"ab cd"), [(0, 2), (3, 5)]);
+        assert_eq!(words(b"ab-cd ef"), [(0, 2), (3, 5), (6, 8)]);
+        // A leading hyphen is part of its word.
+        assert_eq!(words(b"ab -5"), [(0, 2), (3, 5)]);
+        // Periods and commas stay with the word before them.
+        assert_eq!(words(b"ab , cd-."), [(0, 4), (5, 9)]);
+        assert_eq!(words(b""), [(0, 0)]);
+    }
+
+    #[test]
+    fn wrap_line_breakpoint_after_hyphen() {
+        with_device_font(|_mc, df| {
+            let params =
+                EvalParameters::from_parts(Twips::from_pixels(12.0), Twips::from_pixels(0.0), true);
+            let string = WStr::from_units(b"abcd-efgh ijkl");
+            let breakpoint = df.wrap_line(
+                string,
+                params,
+                Twips::from_pixels(35.0),
+                Twips::from_pixels(0.0),
+                true,
+            );
+
+            // The hyphen at index 4 ends the first line.
+            assert_eq!(Some(4), breakpoint);
+        });
+    }
+
+    #[test]
+    fn wrap_line_keeps_nbsp_together() {
+        with_device_font(|_mc, df| {
+            let params =
+                EvalParameters::from_parts(Twips::from_pixels(12.0), Twips::from_pixels(0.0), true);
+            let fs = FormatSpans::from_html(
+                WStr::from_units(b"abcd&nbsp;efgh ijkl"),
+                TextFormat::default(),
+                false,
+                false,
+            );
+            let string = fs.text();
+            assert_eq!(Some(0xA0), string.get(4));
+            assert_eq!(wrap_words(string).collect::<Vec<_>>(), [(0, 9), (10, 14)]);
+
+            // With a plain space, the line would break after `abcd`, as in
+            // `wrap_line_breakpoint_every_word`.
+            let breakpoint = df.wrap_line(
+                string,
+                params,
+                Twips::from_pixels(35.0),
+                Twips::from_pixels(0.0),
+                true,
+            );
+            assert_ne!(Some(4), breakpoint);
+
+            // There is room for `abcd efgh`, which then wraps as a whole.
+            let breakpoint = df.wrap_line(
+                string,
+                params,
+                Twips::from_pixels(65.0),
+                Twips::from_pixels(0.0),
+                true,
+            );
+            assert_eq!(Some(9), breakpoint);
+        });
+    }
 }
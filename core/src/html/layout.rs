@@ -4,7 +4,7 @@ use crate::context::UpdateContext;
 use crate::drawing::Drawing;
 use crate::font::{EvalParameters, Font};
 use crate::html::dimensions::{BoxBounds, Position, Size};
-use crate::html::text_format::{FormatSpans, TextFormat, TextSpan};
+use crate::html::text_format::{FormatSpans, TextDisplay, TextFormat, TextSpan};
 use crate::string::{utils as string_utils, WStr};
 use crate::tag_utils::SwfMovie;
 use gc_arena::Collect;
@@ -135,6 +135,17 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
             + Twips::from_pixels(self.current_line_span.leading)
     }
 
+    /// Calculate how far down the next line starts from this one.
+    ///
+    /// Negative leading pulls the next line closer, but never above the top
+    /// of this line.
+    fn line_advance(&self) -> Twips {
+        max(
+            self.max_font_size + self.line_leading_adjustment(),
+            Twips::ZERO,
+        )
+    }
+
     /// Determine the effective alignment mode for the current line of text.
     ///
     /// This function primarily exists to ensure all bulleted lists are
@@ -338,6 +349,8 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
 
         line_bounds +=
             Position::from((left_adjustment + align_adjustment, Twips::from_pixels(0.0)));
+        // Negative leading can't shrink the line past nothing.
+        let font_leading_adjustment = max(font_leading_adjustment, -line_bounds.height());
         line_bounds += Size::from((Twips::from_pixels(0.0), font_leading_adjustment));
 
         self.current_line = self.boxes.len();
@@ -375,11 +388,7 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
         );
 
         self.cursor.set_x(Twips::from_pixels(0.0));
-        self.cursor += (
-            Twips::from_pixels(0.0),
-            self.max_font_size + self.line_leading_adjustment(),
-        )
-            .into();
+        self.cursor += (Twips::from_pixels(0.0), self.line_advance()).into();
 
         self.is_first_line = true;
         self.has_line_break = true;
@@ -410,11 +419,7 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
         );
 
         self.cursor.set_x(Twips::from_pixels(0.0));
-        self.cursor += (
-            Twips::from_pixels(0.0),
-            self.max_font_size + self.line_leading_adjustment(),
-        )
-            .into();
+        self.cursor += (Twips::from_pixels(0.0), self.line_advance()).into();
 
         self.is_first_line = false;
         self.has_line_break = true;
@@ -455,22 +460,48 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
         }
     }
 
-    fn resolve_font(
-        &mut self,
-        context: &mut UpdateContext<'_, 'gc>,
+    /// Find the font to use for a span.
+    ///
+    /// The span's font may be a comma-separated list of families, such as
+    /// `Arial, Helvetica`, in which case the first available one is used.
+    /// If none of them is available, the device font is used.
+    fn find_font(
+        &self,
+        context: &UpdateContext<'_, 'gc>,
         span: &TextSpan,
         is_device_font: bool,
     ) -> Option<Font<'gc>> {
-        let library = context.library.library_for_movie_mut(self.movie.clone());
-
         // If this text field is set to use device fonts, fallback to using our embedded Noto Sans.
         // Note that the SWF can still contain a DefineFont tag with no glyphs/layout info in this case (see #451).
         // In an ideal world, device fonts would search for a matching font on the system and render it in some way.
-        if let Some(font) = library
-            .get_font_by_name(&span.font.to_utf8_lossy(), span.bold, span.italic)
-            .filter(|f| !is_device_font && f.has_glyphs())
-            .or_else(|| context.library.device_font())
-        {
+        if !is_device_font {
+            if let Some(library) = context.library.library_for_movie(self.movie.clone()) {
+                let name = span.font.to_utf8_lossy();
+                let font = name
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|family| !family.is_empty())
+                    .find_map(|family| {
+                        library
+                            .get_font_by_name(family, span.bold, span.italic)
+                            .filter(|f| f.has_glyphs())
+                    });
+                if font.is_some() {
+                    return font;
+                }
+            }
+        }
+
+        context.library.device_font()
+    }
+
+    fn resolve_font(
+        &mut self,
+        context: &mut UpdateContext<'_, 'gc>,
+        span: &TextSpan,
+        is_device_font: bool,
+    ) -> Option<Font<'gc>> {
+        if let Some(font) = self.find_font(context, span, is_device_font) {
             self.font = Some(font);
             return self.font;
         }
@@ -527,14 +558,7 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
         span: &TextSpan,
         is_device_font: bool,
     ) {
-        let library = context.library.library_for_movie_mut(self.movie.clone());
-
-        if let Some(bullet_font) = library
-            .get_font_by_name(&span.font.to_utf8_lossy(), span.bold, span.italic)
-            .filter(|f| !is_device_font && f.has_glyphs())
-            .or_else(|| context.library.device_font())
-            .or(self.font)
-        {
+        if let Some(bullet_font) = self.find_font(context, span, is_device_font).or(self.font) {
             let mut bullet_cursor = self.cursor;
 
             bullet_cursor.set_x(
@@ -747,6 +771,11 @@ impl<'gc> LayoutBox<'gc> {
     ///
     /// The returned bounds will include both the text bounds itself, as well
     /// as left and right margins on any of the lines.
+    ///
+    /// Text with a `Block` display forms its own paragraphs: it starts on a
+    /// new line where it follows `Inline` text, and vice versa. Newlines
+    /// within `Inline` text don't break the line, so that it stays in the
+    /// flow of the text around it.
     pub fn lower_from_text_spans(
         fs: &FormatSpans,
         context: &mut UpdateContext<'_, 'gc>,
@@ -756,9 +785,17 @@ impl<'gc> LayoutBox<'gc> {
         is_device_font: bool,
     ) -> (Vec<LayoutBox<'gc>>, BoxBounds<Twips>) {
         let mut layout_context = LayoutContext::new(movie, bounds, fs.displayed_text());
+        let mut last_display = None;
 
         for (span_start, _end, span_text, span) in fs.iter_spans() {
             if let Some(font) = layout_context.resolve_font(context, span, is_device_font) {
+                if last_display.map_or(false, |display| display != span.display)
+                    && !layout_context.is_start_of_line()
+                {
+                    layout_context.explicit_newline(context, span_text, 0, span, is_device_font);
+                }
+                last_display = Some(span.display);
+
                 layout_context.newspan(span);
 
                 let params = EvalParameters::from_span(span);
@@ -774,7 +811,7 @@ impl<'gc> LayoutBox<'gc> {
                     };
 
                     match delimiter {
-                        Some(b'\n' | b'\r') => {
+                        Some(b'\n' | b'\r') if span.display == TextDisplay::Block => {
                             layout_context.explicit_newline(context, text, 0, span, is_device_font)
                         }
                         Some(b'\t') => layout_context.tab(),
@@ -944,5 +981,10 @@ pub struct LayoutMetrics {
     pub width: Twips,
     pub height: Twips,
 
+    /// The left edge of the line's content, relative to the text field.
+    ///
+    /// This already includes the offset applied by `fixup_line` for the
+    /// line's margins, indent and alignment, so centered and right-aligned
+    /// lines report where their text actually starts.
     pub x: Twips,
 }
@@ -1,7 +1,7 @@
 //! Tests for HTML module
 
 use crate::html::dimensions::{BoxBounds, Position, Size};
-use crate::html::text_format::{FormatSpans, TextFormat, TextSpan};
+use crate::html::text_format::{FormatSpans, TextDisplay, TextFormat, TextSpan};
 use crate::string::{WStr, WString};
 use swf::{Rectangle, Twips};
 
@@ -864,3 +864,370 @@ fn formatspans_replace_text_degenerate() {
     assert_eq!((0, 1), fs.get_span_boundaries(0, 5));
     assert_eq!((1, 2), fs.get_span_boundaries(5, 9));
 }
+
+#[test]
+fn formatspans_from_html_block_display() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(b"<p>abc<b>def</b></p>"),
+        TextFormat::default(),
+        true,
+        false,
+    );
+
+    assert_eq!(WStr::from_units(b"abcdef\n"), fs.text());
+    assert_eq!(Some(TextDisplay::Block), fs.get_text_format(0, 7).display);
+}
+
+#[test]
+fn formatspans_set_inline_display() {
+    let mut fs = FormatSpans::from_text(WString::from_utf8("abcdefghi"), TextFormat::default());
+    fs.set_text_format(
+        3,
+        6,
+        &TextFormat {
+            display: Some(TextDisplay::Inline),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(WStr::from_units(b"abcdefghi"), fs.text());
+    assert_eq!(Some(TextDisplay::Inline), fs.get_text_format(3, 6).display);
+    assert_eq!(Some(TextDisplay::Block), fs.get_text_format(0, 3).display);
+    assert_eq!(None, fs.get_text_format(0, 6).display);
+}
+
+#[test]
+fn formatspans_from_html_condense_white() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(b"  abc \n\t def<b>  ghi </b>\n jkl  "),
+        TextFormat::default(),
+        false,
+        true,
+    );
+    let condensed = FormatSpans::from_html(
+        WStr::from_units(b"abc def<b> ghi </b>jkl "),
+        TextFormat::default(),
+        false,
+        false,
+    );
+
+    assert_eq!(WStr::from_units(b"abc def ghi jkl "), fs.text());
+    assert_eq!(condensed.to_html(), fs.to_html());
+}
+
+#[test]
+fn formatspans_from_html_without_condense_white() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(b"abc   def"),
+        TextFormat::default(),
+        false,
+        false,
+    );
+    let round_tripped = FormatSpans::from_html(&fs.to_html(), TextFormat::default(), false, false);
+
+    assert_eq!(WStr::from_units(b"abc   def"), fs.text());
+    assert_eq!(fs.text(), round_tripped.text());
+}
+
+#[test]
+fn formatspans_password_text_is_not_masked() {
+    let mut fs = FormatSpans::from_text(WString::from_utf8("hunter2"), TextFormat::default());
+    fs.hide_text();
+
+    assert_eq!(WStr::from_units(b"hunter2"), fs.text());
+    assert_eq!(WStr::from_units(b"*******"), fs.displayed_text());
+}
+
+#[test]
+fn formatspans_password_html_is_masked() {
+    let mut fs = FormatSpans::from_html(
+        WStr::from_units(b"<b>hunter</b>2"),
+        TextFormat::default(),
+        false,
+        false,
+    );
+    fs.hide_text();
+
+    let html = fs.to_html();
+    assert!(html.find(WStr::from_units(b"hunter")).is_none());
+    assert!(html.find(WStr::from_units(b"<B>******</B>*")).is_some());
+
+    fs.clear_displayed_text();
+    assert!(fs
+        .to_html()
+        .find(WStr::from_units(b"<B>hunter</B>2"))
+        .is_some());
+}
+
+#[test]
+fn formatspans_from_html_unsupported_tags_keep_text() {
+    let (fs, unsupported_tags) = FormatSpans::from_html_with_unsupported_tags(
+        WStr::from_units(b"abc<span>def</span><BLINK>ghi<foo>jkl</foo></BLINK><span>mno</span>"),
+        TextFormat::default(),
+        false,
+        false,
+    );
+
+    assert_eq!(WStr::from_units(b"abcdefghijklmno"), fs.text());
+    assert_eq!(
+        vec![b"span".to_vec(), b"blink".to_vec(), b"foo".to_vec()],
+        unsupported_tags
+    );
+}
+
+#[test]
+fn formatspans_set_text_format_url_makes_link() {
+    let mut fs = FormatSpans::from_text(WString::from_utf8("click here"), TextFormat::default());
+    let link = TextFormat {
+        url: Some(WString::from_utf8("event:clicked")),
+        target: Some(WString::from_utf8("_blank")),
+        ..Default::default()
+    };
+    fs.set_text_format(6, 10, &link);
+
+    let tf = fs.get_text_format(6, 10);
+    assert_eq!(Some(WString::from_utf8("event:clicked")), tf.url);
+    assert_eq!(Some(WString::from_utf8("_blank")), tf.target);
+    assert_eq!(Some(WString::new()), fs.get_text_format(0, 5).url);
+    assert_eq!(None, fs.get_text_format(0, 10).url);
+    assert!(fs
+        .to_html()
+        .to_utf8_lossy()
+        .contains("<A HREF=\"event:clicked\" TARGET=\"_blank\">here</A>"));
+}
+
+#[test]
+fn formatspans_set_text_format_keeps_unset_attributes() {
+    let base = TextFormat {
+        font: Some(WString::from_utf8("Verdana")),
+        size: Some(24.0),
+        color: Some(swf::Color::from_rgb(0xFF0000, 0xFF)),
+        bold: Some(true),
+        underline: Some(true),
+        letter_spacing: Some(2.0),
+        ..TextFormat::default()
+    };
+    let mut fs = FormatSpans::from_text(WString::from_utf8("abcdef"), base.clone());
+    let partial = TextFormat {
+        italic: Some(true),
+        ..Default::default()
+    };
+    fs.set_text_format(0, 3, &partial);
+
+    let tf = fs.get_text_format(0, 3);
+    assert_eq!(Some(true), tf.italic);
+    assert_eq!(base.font, tf.font);
+    assert_eq!(base.size, tf.size);
+    assert_eq!(base.color, tf.color);
+    assert_eq!(base.bold, tf.bold);
+    assert_eq!(base.underline, tf.underline);
+    assert_eq!(base.letter_spacing, tf.letter_spacing);
+    assert_eq!(Some(false), fs.get_text_format(3, 6).italic);
+}
+
+#[test]
+fn formatspans_from_html_nested_lists() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(b"<ul><li>abc</li><ol><li>def</li></ol></ul>"),
+        TextFormat::default(),
+        true,
+        false,
+    );
+
+    assert_eq!(WStr::from_units(b"abc\ndef\n"), fs.text());
+    let outer = fs.get_text_format(0, 3);
+    let inner = fs.get_text_format(4, 7);
+    assert_eq!(Some(true), outer.bullet);
+    assert_eq!(Some(true), inner.bullet);
+    assert_eq!(outer.indent, inner.indent);
+    assert_eq!(outer.block_indent, inner.block_indent);
+    assert_eq!(outer.left_margin, inner.left_margin);
+}
+
+#[test]
+fn textformat_matches_defined() {
+    let format = TextFormat {
+        font: Some(WString::from_utf8("Verdana")),
+        bold: Some(true),
+        italic: None,
+        ..TextFormat::default()
+    };
+
+    assert!(format.matches_defined(&TextFormat::default()));
+    assert!(format.matches_defined(&TextFormat {
+        bold: Some(true),
+        ..TextFormat::default()
+    }));
+    assert!(!format.matches_defined(&TextFormat {
+        bold: Some(false),
+        ..TextFormat::default()
+    }));
+    assert!(!format.matches_defined(&TextFormat {
+        italic: Some(false),
+        ..TextFormat::default()
+    }));
+}
+
+#[test]
+fn formatspans_has_uniform_format() {
+    let mut fs = FormatSpans::from_text(
+        WString::from_utf8("abcdef"),
+        TextFormat {
+            bold: Some(false),
+            ..TextFormat::default()
+        },
+    );
+    let bold = TextFormat {
+        bold: Some(true),
+        ..TextFormat::default()
+    };
+    fs.set_text_format(2, 6, &bold);
+
+    assert!(fs.has_uniform_format(2, 6, &bold));
+    assert!(fs.has_uniform_format(3, 5, &bold));
+    assert!(!fs.has_uniform_format(0, 6, &bold));
+    assert!(!fs.has_uniform_format(0, 2, &bold));
+}
+
+#[test]
+fn formatspans_set_default_format_only_affects_new_text() {
+    let mut fs = FormatSpans::from_text(
+        WString::from_utf8("abc"),
+        TextFormat {
+            bold: Some(false),
+            ..TextFormat::default()
+        },
+    );
+    fs.set_default_format(TextFormat {
+        bold: Some(true),
+        ..TextFormat::default()
+    });
+
+    assert_eq!(Some(false), fs.get_text_format(0, 3).bold);
+
+    fs.replace_text(3, 3, WStr::from_units(b"def"), None);
+
+    assert_eq!(WStr::from_units(b"abcdef"), fs.text());
+    assert_eq!(Some(false), fs.get_text_format(0, 3).bold);
+    assert_eq!(Some(true), fs.get_text_format(3, 6).bold);
+}
+
+#[test]
+fn formatspans_from_html_entities() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(b"a&amp;b&lt;&gt;&quot;&apos;&nbsp;&#65;&#x42;&bogus;"),
+        TextFormat::default(),
+        false,
+        false,
+    );
+
+    // A non-breaking space is never used as a word wrap opportunity, as wrapping only splits at spaces.
+    assert_eq!(WStr::from_units(b"a&b<>\"'\xA0AB&bogus;"), fs.text());
+}
+
+#[test]
+fn formatspans_has_same_content() {
+    let fs1 = FormatSpans::from_text(WString::from_utf8("abc"), TextFormat::default());
+    let mut fs2 = FormatSpans::from_text(WString::from_utf8("abc"), TextFormat::default());
+    let fs3 = FormatSpans::from_text(WString::from_utf8("abd"), TextFormat::default());
+
+    assert!(fs1.has_same_content(&fs2));
+    assert!(!fs1.has_same_content(&fs3));
+
+    fs2.set_text_format(
+        0,
+        1,
+        &TextFormat {
+            bold: Some(true),
+            ..TextFormat::default()
+        },
+    );
+
+    assert!(!fs1.has_same_content(&fs2));
+}
+
+#[test]
+fn formatspans_from_html_paragraph_align() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(
+            b"<p align=\"left\">a</p><p align=\"center\">b</p><p align=\"right\">c</p><p align=\"justify\">d</p>",
+        ),
+        TextFormat::default(),
+        true,
+        false,
+    );
+
+    assert_eq!(Some(swf::TextAlign::Left), fs.get_text_format(0, 1).align);
+    assert_eq!(Some(swf::TextAlign::Center), fs.get_text_format(2, 3).align);
+    assert_eq!(Some(swf::TextAlign::Right), fs.get_text_format(4, 5).align);
+    assert_eq!(
+        Some(swf::TextAlign::Justify),
+        fs.get_text_format(6, 7).align
+    );
+}
+
+#[test]
+fn formatspans_from_html_div_align() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(
+            b"<div align=\"Left\">a</div><DIV ALIGN=\"CENTER\">b</DIV><div align=\"right\">c</div><div align=\"JUSTIFY\">d</div>",
+        ),
+        TextFormat::default(),
+        true,
+        false,
+    );
+
+    assert_eq!(WStr::from_units(b"a\nb\nc\nd\n"), fs.text());
+    assert_eq!(Some(swf::TextAlign::Left), fs.get_text_format(0, 1).align);
+    assert_eq!(Some(swf::TextAlign::Center), fs.get_text_format(2, 3).align);
+    assert_eq!(Some(swf::TextAlign::Right), fs.get_text_format(4, 5).align);
+    assert_eq!(
+        Some(swf::TextAlign::Justify),
+        fs.get_text_format(6, 7).align
+    );
+}
+
+#[test]
+fn formatspans_from_html_paragraph_align_round_trip() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(b"<P ALIGN=\"CENTER\">abc</P>"),
+        TextFormat::default(),
+        true,
+        false,
+    );
+    assert_eq!(Some(swf::TextAlign::Center), fs.get_text_format(0, 3).align);
+
+    let round_tripped = FormatSpans::from_html(&fs.to_html(), TextFormat::default(), true, false);
+    assert_eq!(
+        Some(swf::TextAlign::Center),
+        round_tripped.get_text_format(0, 3).align
+    );
+}
+
+#[test]
+fn formatspans_from_html_font_face() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(
+            b"<font face=\"Arial\">a</font><font face=\"Times New Roman, _serif\">b</font>",
+        ),
+        TextFormat::default(),
+        false,
+        false,
+    );
+
+    assert_eq!(
+        Some(WString::from_utf8("Arial")),
+        fs.get_text_format(0, 1).font
+    );
+    // Lists of families are kept as they are; the first available one is picked during layout.
+    assert_eq!(
+        Some(WString::from_utf8("Times New Roman, _serif")),
+        fs.get_text_format(1, 2).font
+    );
+
+    let round_tripped = FormatSpans::from_html(&fs.to_html(), TextFormat::default(), false, false);
+    assert_eq!(
+        Some(WString::from_utf8("Times New Roman, _serif")),
+        round_tripped.get_text_format(1, 2).font
+    );
+}
@@ -12,6 +12,51 @@ use std::collections::VecDeque;
 use std::fmt::Write;
 use std::sync::Arc;
 
+/// Collapse every run of whitespace in `src` into a single space, as done by
+/// `TextField.condenseWhite`.
+///
+/// Leading whitespace is dropped entirely if `skip_leading` is set, which is
+/// used when the preceding text already ends with whitespace.
+fn condense_whitespace(src: &WStr, skip_leading: bool) -> WString {
+    let mut result = WString::new();
+    let mut in_whitespace = skip_leading;
+    for c in src.iter() {
+        if matches!(u8::try_from(c), Ok(b' ' | b'\t' | b'\r' | b'\n')) {
+            if !in_whitespace {
+                result.push_byte(b' ');
+                in_whitespace = true;
+            }
+        } else {
+            result.push(c);
+            in_whitespace = false;
+        }
+    }
+    result
+}
+
+/// Whether the given (lowercase) HTML tag is understood by `FormatSpans::from_html`.
+///
+/// Flash Player accepts `<ul>` and `<ol>` around list items, but they have no
+/// effect of their own: every `<li>` is bulleted (never numbered), and nested
+/// lists are not indented any further.
+fn is_supported_html_tag(name: &[u8]) -> bool {
+    matches!(
+        name,
+        b"a" | b"b"
+            | b"br"
+            | b"div"
+            | b"font"
+            | b"i"
+            | b"li"
+            | b"ol"
+            | b"p"
+            | b"sbr"
+            | b"textformat"
+            | b"u"
+            | b"ul"
+    )
+}
+
 /// Replace HTML entities with their equivalent characters.
 ///
 /// Unknown entities will be ignored.
@@ -48,7 +93,7 @@ fn process_html_entity(src: &WStr) -> Option<WString> {
                     result_str.push_byte(b'\xA0');
                 } else if s.len() >= 2 && s.at(0) == b'#' as u16 {
                     // Number entity: &#nnnn; or &#xhhhh;
-                    let (digits, radix) = if src.at(1) == b'x' as u16 {
+                    let (digits, radix) = if s.at(1) == b'x' as u16 {
                         // Only trailing 4 hex digits are used.
                         let start = usize::max(s.len(), 6) - 4;
                         (&s[start..], 16)
@@ -98,6 +143,17 @@ fn process_html_entity(src: &WStr) -> Option<WString> {
     Some(result_str)
 }
 
+/// Whether a piece of formatted text forms its own block, or flows inline with
+/// the text around it.
+///
+/// This corresponds to `flash.text.TextFormatDisplay`, and to the CSS
+/// `display` property of a style sheet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextDisplay {
+    Block,
+    Inline,
+}
+
 /// A set of text formatting options to be applied to some part, or the whole
 /// of, a given text field.
 ///
@@ -107,7 +163,7 @@ fn process_html_entity(src: &WStr) -> Option<WString> {
 /// means that multiple regions of text apply. When setting the format of a
 /// particular region of text, `None` means that the existing setting for that
 /// property will be retained.
-#[derive(Clone, Debug, Collect, Default)]
+#[derive(Clone, Debug, Collect, Default, PartialEq)]
 #[collect(require_static)]
 pub struct TextFormat {
     pub font: Option<WString>,
@@ -128,6 +184,7 @@ pub struct TextFormat {
     pub bullet: Option<bool>,
     pub url: Option<WString>,
     pub target: Option<WString>,
+    pub display: Option<TextDisplay>,
 }
 
 impl TextFormat {
@@ -180,6 +237,7 @@ impl TextFormat {
             // TODO: These are probably empty strings by default
             url: Some(WString::new()),
             target: Some(WString::new()),
+            display: Some(TextDisplay::Block),
         }
     }
 
@@ -273,9 +331,46 @@ impl TextFormat {
             } else {
                 None
             },
+            display: if self.display == rhs.display {
+                self.display
+            } else {
+                None
+            },
         }
     }
 
+    /// Determine if every property defined in `pattern` is also defined in
+    /// this format, with the same value.
+    ///
+    /// Properties that `pattern` leaves undefined are ignored. When `self`
+    /// comes from `FormatSpans::get_text_format`, this tells whether a range
+    /// of text uniformly has the properties of `pattern`.
+    pub fn matches_defined(&self, pattern: &TextFormat) -> bool {
+        fn matches<T: PartialEq>(value: &Option<T>, pattern: &Option<T>) -> bool {
+            pattern.is_none() || value == pattern
+        }
+
+        matches(&self.font, &pattern.font)
+            && matches(&self.size, &pattern.size)
+            && matches(&self.color, &pattern.color)
+            && matches(&self.align, &pattern.align)
+            && matches(&self.bold, &pattern.bold)
+            && matches(&self.italic, &pattern.italic)
+            && matches(&self.underline, &pattern.underline)
+            && matches(&self.left_margin, &pattern.left_margin)
+            && matches(&self.right_margin, &pattern.right_margin)
+            && matches(&self.indent, &pattern.indent)
+            && matches(&self.block_indent, &pattern.block_indent)
+            && matches(&self.kerning, &pattern.kerning)
+            && matches(&self.leading, &pattern.leading)
+            && matches(&self.letter_spacing, &pattern.letter_spacing)
+            && matches(&self.tab_stops, &pattern.tab_stops)
+            && matches(&self.bullet, &pattern.bullet)
+            && matches(&self.url, &pattern.url)
+            && matches(&self.target, &pattern.target)
+            && matches(&self.display, &pattern.display)
+    }
+
     /// Given two text formats, construct a new `TextFormat` where properties
     /// defined in either `TextFormat` are defined.
     ///
@@ -300,6 +395,7 @@ impl TextFormat {
             bullet: self.bullet.or(rhs.bullet),
             url: self.url.or(rhs.url),
             target: self.target.or(rhs.target),
+            display: self.display.or(rhs.display),
         }
     }
 }
@@ -313,7 +409,7 @@ impl TextFormat {
 ///
 /// This struct also contains a resolved version of the `TextFormat` structure
 /// listed above.
-#[derive(Clone, Debug, Collect)]
+#[derive(Clone, Debug, Collect, PartialEq)]
 #[collect(require_static)]
 pub struct TextSpan {
     /// How many characters are subsumed by this text span.
@@ -340,6 +436,7 @@ pub struct TextSpan {
     pub bullet: bool,
     pub url: WString,
     pub target: WString,
+    pub display: TextDisplay,
 }
 
 impl Default for TextSpan {
@@ -369,6 +466,7 @@ impl Default for TextSpan {
             bullet: false,
             url: WString::new(),
             target: WString::new(),
+            display: TextDisplay::Block,
         }
     }
 }
@@ -409,6 +507,7 @@ impl TextSpan {
             && self.bullet == rhs.bullet
             && self.url == rhs.url
             && self.target == rhs.target
+            && self.display == rhs.display
     }
 
     /// Apply a text format to this text span.
@@ -486,6 +585,10 @@ impl TextSpan {
         if let Some(target) = &tf.target {
             self.target = target.clone();
         }
+
+        if let Some(display) = tf.display {
+            self.display = display;
+        }
     }
 
     /// Convert the text span into a format.
@@ -511,6 +614,7 @@ impl TextSpan {
             bullet: Some(self.bullet),
             url: Some(self.url.clone()),
             target: Some(self.target.clone()),
+            display: Some(self.display),
         }
     }
 }
@@ -568,7 +672,25 @@ impl FormatSpans {
     /// a handful of presentational attributes in the HTML tree to generate
     /// styling. There's also a `lower_from_css` that respects both
     /// presentational markup and CSS stylesheets.
-    pub fn from_html(html: &WStr, default_format: TextFormat, is_multiline: bool) -> Self {
+    pub fn from_html(
+        html: &WStr,
+        default_format: TextFormat,
+        is_multiline: bool,
+        condense_white: bool,
+    ) -> Self {
+        Self::from_html_with_unsupported_tags(html, default_format, is_multiline, condense_white).0
+    }
+
+    /// Lower an HTML tree into text-span representation, like `from_html`.
+    ///
+    /// Unsupported tags are dropped, but their contents are kept. The
+    /// lowercase names of those tags are returned as well, each one once.
+    pub fn from_html_with_unsupported_tags(
+        html: &WStr,
+        default_format: TextFormat,
+        is_multiline: bool,
+        condense_white: bool,
+    ) -> (Self, Vec<Vec<u8>>) {
         let mut format_stack = vec![default_format.clone()];
         let mut text = WString::new();
         let mut spans: Vec<TextSpan> = Vec::new();
@@ -603,6 +725,8 @@ impl FormatSpans {
         let mut opened_buffer: Vec<u8> = Vec::new();
         let mut opened_starts = Vec::new();
 
+        let mut ignored_tags: Vec<Vec<u8>> = Vec::new();
+
         let mut reader = Reader::from_reader(&raw_bytes[..]);
         reader.expand_empty_elements(true);
         reader.check_end_names(false);
@@ -630,7 +754,12 @@ impl FormatSpans {
                         })
                     };
                     let mut format = format_stack.last().unwrap().clone();
-                    match &e.name().into_inner().to_ascii_lowercase()[..] {
+                    let tag_name = e.name().into_inner().to_ascii_lowercase();
+                    if !is_supported_html_tag(&tag_name) && !ignored_tags.contains(&tag_name) {
+                        ignored_tags.push(tag_name.clone());
+                    }
+
+                    match &tag_name[..] {
                         b"br" => {
                             if is_multiline {
                                 text.push_byte(b'\n');
@@ -653,14 +782,17 @@ impl FormatSpans {
                             // Skip push to `format_stack`.
                             continue;
                         }
-                        b"p" if is_multiline => {
+                        b"p" | b"div" if is_multiline => {
+                            // `to_html` writes these in uppercase, so match them case-insensitively.
                             if let Some(align) = attribute(b"align") {
-                                if align == WStr::from_units(b"left") {
+                                if align.eq_ignore_case(WStr::from_units(b"left")) {
                                     format.align = Some(swf::TextAlign::Left)
-                                } else if align == WStr::from_units(b"center") {
+                                } else if align.eq_ignore_case(WStr::from_units(b"center")) {
                                     format.align = Some(swf::TextAlign::Center)
-                                } else if align == WStr::from_units(b"right") {
+                                } else if align.eq_ignore_case(WStr::from_units(b"right")) {
                                     format.align = Some(swf::TextAlign::Right)
+                                } else if align.eq_ignore_case(WStr::from_units(b"justify")) {
+                                    format.align = Some(swf::TextAlign::Justify)
                                 }
                             }
                         }
@@ -763,6 +895,16 @@ impl FormatSpans {
                 }
                 Ok(Event::Text(e)) if !e.is_empty() => {
                     let e = decode_to_wstr(&e.into_inner());
+                    let e = if condense_white {
+                        let skip_leading =
+                            text.is_empty() || text.ends_with(b' ') || text.ends_with(b'\n');
+                        condense_whitespace(&e, skip_leading)
+                    } else {
+                        e
+                    };
+                    if e.is_empty() {
+                        continue;
+                    }
                     let e = process_html_entity(&e).unwrap_or(e);
                     let format = format_stack.last().unwrap().clone();
                     text.push_str(&e);
@@ -787,7 +929,7 @@ impl FormatSpans {
                             // Skip pop from `format_stack`.
                             continue;
                         }
-                        b"p" | b"li" if is_multiline => {
+                        b"p" | b"div" | b"li" if is_multiline => {
                             text.push_byte(b'\n');
                             if let Some(span) = spans.last_mut() {
                                 span.span_length += 1;
@@ -806,12 +948,13 @@ impl FormatSpans {
             }
         }
 
-        Self {
+        let format_spans = Self {
             text,
             displayed_text: WString::new(),
             spans,
             default_format,
-        }
+        };
+        (format_spans, ignored_tags)
     }
 
     pub fn default_format(&self) -> &TextFormat {
@@ -839,6 +982,15 @@ impl FormatSpans {
         &self.text
     }
 
+    /// Whether both sets of spans contain the same text, formatted the same
+    /// way.
+    ///
+    /// The displayed text and default format are not compared, as they don't
+    /// contribute to the content itself.
+    pub fn has_same_content(&self, other: &Self) -> bool {
+        self.text == other.text && self.spans == other.spans
+    }
+
     pub fn displayed_text(&self) -> &WStr {
         if self.has_displayed_text() {
             &self.displayed_text
@@ -1058,6 +1210,15 @@ impl FormatSpans {
         merged_fmt
     }
 
+    /// Determine if all of the text from the start index to the end index has
+    /// every property defined in `pattern`.
+    ///
+    /// For example, a `pattern` with only `bold` set to `Some(true)` tests
+    /// whether the whole range is bold.
+    pub fn has_uniform_format(&self, from: usize, to: usize, pattern: &TextFormat) -> bool {
+        self.get_text_format(from, to).matches_defined(pattern)
+    }
+
     /// Change some portion of the text to have a particular set of text
     /// attributes.
     pub fn set_text_format(&mut self, from: usize, to: usize, fmt: &TextFormat) {
@@ -1156,6 +1317,10 @@ impl FormatSpans {
         TextSpanIter::for_format_spans(self)
     }
 
+    /// Serialize the spans into HTML.
+    ///
+    /// This uses the displayed text, so the text of password fields stays
+    /// masked.
     pub fn to_html(&self) -> WString {
         let mut spans = self.iter_spans();
         let mut state = if let Some((_start, _end, text, span)) = spans.next() {
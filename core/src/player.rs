@@ -8,7 +8,7 @@ use crate::avm1::{Activation, ActivationIdentifier};
 use crate::avm1::{ScriptObject, TObject, Value};
 use crate::avm2::{
     object::LoaderInfoObject, object::TObject as _, Activation as Avm2Activation, Avm2, CallStack,
-    Object as Avm2Object,
+    EventObject as Avm2EventObject, Object as Avm2Object,
 };
 use crate::backend::{
     audio::{AudioBackend, AudioManager},
@@ -1048,8 +1048,15 @@ impl Player {
 
             // keyPress events take precedence over text input.
             if !key_press_handled {
+                // Tab is handled as a key press below, as whether it inserts a tab depends on
+                // `keyFocusChange`.
                 if let PlayerEvent::TextInput { codepoint } = event {
-                    if let Some(text) = context.focus_tracker.get().and_then(|o| o.as_edit_text()) {
+                    if let Some(text) = context
+                        .focus_tracker
+                        .get()
+                        .and_then(|o| o.as_edit_text())
+                        .filter(|_| codepoint != '\t')
+                    {
                         text.text_input(codepoint, context);
                     }
                 }
@@ -1058,6 +1065,25 @@ impl Player {
                         text.text_control_input(code, context);
                     }
                 }
+
+                // Tab moves the focus, even out of editable text fields. In AVM2 this can be
+                // prevented by cancelling `keyFocusChange`, in which case a focused text field
+                // gets a tab character instead.
+                if let PlayerEvent::KeyDown {
+                    key_code: KeyCode::Tab,
+                    ..
+                } = event
+                {
+                    let reverse = context.input.is_key_down(KeyCode::Shift);
+                    if Self::dispatch_key_focus_change(context) {
+                        let tracker = context.focus_tracker;
+                        tracker.cycle(context, reverse);
+                    } else if let Some(text) =
+                        context.focus_tracker.get().and_then(|o| o.as_edit_text())
+                    {
+                        text.text_input('\t', context);
+                    }
+                }
             }
 
             // Propagate clip events.
@@ -1139,8 +1165,16 @@ impl Player {
             self.mouse_position = inverse_view_matrix * Point::from_pixels(x, y);
 
             // Update the dragged object here to keep it constantly in sync with the mouse position.
+            let is_mouse_move = matches!(event, PlayerEvent::MouseMove { .. });
             self.mutate_with_update_context(|context| {
                 Self::update_drag(context);
+
+                if is_mouse_move {
+                    if let Some(text) = Self::text_selection_drag_target(context) {
+                        let mouse_position = *context.mouse_position;
+                        text.drag_selection(context, mouse_position);
+                    }
+                }
             });
 
             let is_mouse_moved = prev_mouse_position != self.mouse_position;
@@ -1174,6 +1208,44 @@ impl Player {
         }
     }
 
+    /// Dispatch an AVM2 `keyFocusChange` event to the focused object, or the stage.
+    ///
+    /// Returns `false` if the focus change was cancelled by a listener.
+    fn dispatch_key_focus_change(context: &mut UpdateContext<'_, '_>) -> bool {
+        if !context.is_action_script_3() {
+            return true;
+        }
+
+        let focused = context.focus_tracker.get();
+        let mut activation = Avm2Activation::from_nothing(context.reborrow());
+        let event = Avm2EventObject::focus_event(
+            &mut activation,
+            "keyFocusChange",
+            true,
+            None,
+            KeyCode::Tab as u32,
+        );
+        let target = focused
+            .unwrap_or_else(|| activation.context.stage.into())
+            .object2()
+            .coerce_to_object(&mut activation)
+            .expect("DisplayObject is not an object!");
+
+        Avm2::dispatch_event(&mut activation.context, event, target);
+        !event.as_event().map_or(false, |event| event.is_cancelled())
+    }
+
+    /// The text field whose text is being selected by dragging the mouse, if any.
+    fn text_selection_drag_target<'gc>(context: &UpdateContext<'_, 'gc>) -> Option<EditText<'gc>> {
+        if !context.input.is_mouse_down() {
+            return None;
+        }
+
+        context
+            .mouse_down_object
+            .and_then(|object| object.as_displayobject().as_edit_text())
+    }
+
     /// Update dragged object, if any.
     pub fn update_drag(context: &mut UpdateContext<'_, '_>) {
         let mouse_position = *context.mouse_position;
@@ -1595,6 +1667,12 @@ impl Player {
                 Avm1::run_frame(context);
             }
             AudioManager::update_sounds(context);
+
+            // Keep scrolling a text field while its selection is dragged outside of it.
+            if let Some(text) = Self::text_selection_drag_target(context) {
+                let mouse_position = *context.mouse_position;
+                text.auto_scroll_selection(context, mouse_position);
+            }
         });
 
         self.needs_render = true;
@@ -1633,6 +1711,7 @@ impl Player {
             };
 
             stage.render(&mut render_context);
+            root_data.focus_tracker.render_highlight(&mut render_context);
 
             #[cfg(feature = "egui")]
             {
@@ -10,20 +10,48 @@ use winit::event_loop::EventLoop;
 
 /// Converts a `VirtualKeyCode` and `ModifiersState` to a Ruffle `TextControlCode`.
 /// Returns `None` if there is no match.
-/// TODO: Handle Ctrl+Arrows and Home/End keys
 pub fn winit_to_ruffle_text_control(
     key: VirtualKeyCode,
     modifiers: ModifiersState,
 ) -> Option<TextControlCode> {
     let shift = modifiers.contains(ModifiersState::SHIFT);
-    let ctrl_cmd = modifiers.contains(ModifiersState::CTRL)
-        || (modifiers.contains(ModifiersState::LOGO) && cfg!(target_os = "macos"));
+    let macos_cmd = modifiers.contains(ModifiersState::LOGO) && cfg!(target_os = "macos");
+    let ctrl_cmd = modifiers.contains(ModifiersState::CTRL) || macos_cmd;
     if ctrl_cmd {
         match key {
             VirtualKeyCode::A => Some(TextControlCode::SelectAll),
             VirtualKeyCode::C => Some(TextControlCode::Copy),
             VirtualKeyCode::V => Some(TextControlCode::Paste),
             VirtualKeyCode::X => Some(TextControlCode::Cut),
+            // On macOS, Cmd+Left/Right moves to the start/end of the line.
+            VirtualKeyCode::Left if macos_cmd => {
+                if shift {
+                    Some(TextControlCode::SelectLineStart)
+                } else {
+                    Some(TextControlCode::MoveLineStart)
+                }
+            }
+            VirtualKeyCode::Right if macos_cmd => {
+                if shift {
+                    Some(TextControlCode::SelectLineEnd)
+                } else {
+                    Some(TextControlCode::MoveLineEnd)
+                }
+            }
+            VirtualKeyCode::Left => {
+                if shift {
+                    Some(TextControlCode::SelectLeftWord)
+                } else {
+                    Some(TextControlCode::MoveLeftWord)
+                }
+            }
+            VirtualKeyCode::Right => {
+                if shift {
+                    Some(TextControlCode::SelectRightWord)
+                } else {
+                    Some(TextControlCode::MoveRightWord)
+                }
+            }
             _ => None,
         }
     } else {
@@ -44,6 +72,48 @@ pub fn winit_to_ruffle_text_control(
                     Some(TextControlCode::MoveRight)
                 }
             }
+            VirtualKeyCode::Up => {
+                if shift {
+                    Some(TextControlCode::SelectUp)
+                } else {
+                    Some(TextControlCode::MoveUp)
+                }
+            }
+            VirtualKeyCode::Down => {
+                if shift {
+                    Some(TextControlCode::SelectDown)
+                } else {
+                    Some(TextControlCode::MoveDown)
+                }
+            }
+            VirtualKeyCode::PageUp => {
+                if shift {
+                    Some(TextControlCode::SelectPageUp)
+                } else {
+                    Some(TextControlCode::MovePageUp)
+                }
+            }
+            VirtualKeyCode::PageDown => {
+                if shift {
+                    Some(TextControlCode::SelectPageDown)
+                } else {
+                    Some(TextControlCode::MovePageDown)
+                }
+            }
+            VirtualKeyCode::Home => {
+                if shift {
+                    Some(TextControlCode::SelectLineStart)
+                } else {
+                    Some(TextControlCode::MoveLineStart)
+                }
+            }
+            VirtualKeyCode::End => {
+                if shift {
+                    Some(TextControlCode::SelectLineEnd)
+                } else {
+                    Some(TextControlCode::MoveLineEnd)
+                }
+            }
             _ => None,
         }
     }
@@ -0,0 +1,97 @@
+//! Compares the SIMD CPU blur against a straightforward scalar implementation of the same passes.
+//!
+//! Run with `cargo bench -p ruffle_render --bench cpu_blur`.
+
+use ruffle_render::filters::blur::{apply_blur_on_cpu, passes, BlurPass};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use swf::{BlurFilter, BlurFilterFlags, Fixed16};
+
+const SIZE: usize = 512;
+const ITERATIONS: u32 = 10;
+
+/// Samples every pixel of the kernel for every output pixel, one channel at a time,
+/// the way the GPU shader does.
+fn scalar_blur(filter: &BlurFilter, source: &[u8]) -> Vec<u8> {
+    let mut image: Vec<f32> = source.iter().map(|&channel| channel as f32).collect();
+    for BlurPass {
+        horizontal,
+        m,
+        alpha,
+        ..
+    } in passes(filter)
+    {
+        let m = m as isize;
+        let scale = 1.0 / (2.0 * m as f32 + 1.0 + 2.0 * alpha);
+        let mut result = vec![0.0; image.len()];
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                for channel in 0..4 {
+                    let sample = |offset: isize| {
+                        let (sx, sy) = if horizontal {
+                            (x as isize + offset, y as isize)
+                        } else {
+                            (x as isize, y as isize + offset)
+                        };
+                        let sx = sx.clamp(0, SIZE as isize - 1) as usize;
+                        let sy = sy.clamp(0, SIZE as isize - 1) as usize;
+                        image[(sy * SIZE + sx) * 4 + channel]
+                    };
+                    let window: f32 = (-m..=m).map(sample).sum();
+                    let total = window + (sample(-m - 1) + sample(m + 1)) * alpha;
+                    result[(y * SIZE + x) * 4 + channel] = (total * scale).round();
+                }
+            }
+        }
+        image = result;
+    }
+    image.into_iter().map(|channel| channel as u8).collect()
+}
+
+fn time(name: &str, mut f: impl FnMut()) {
+    f();
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        f();
+        total += start.elapsed();
+    }
+    println!("{name}: {:?} per iteration", total / ITERATIONS);
+}
+
+fn main() {
+    let filter = BlurFilter {
+        blur_x: Fixed16::from_f32(40.0),
+        blur_y: Fixed16::from_f32(40.0),
+        flags: BlurFilterFlags::from_passes(1),
+    };
+    let source: Vec<u8> = (0..SIZE * SIZE * 4).map(|i| (i % 251) as u8).collect();
+
+    let simd = apply_blur_on_cpu(
+        &filter,
+        &source,
+        SIZE as u32,
+        SIZE as u32,
+        (0, 0),
+        (SIZE as u32, SIZE as u32),
+    );
+    assert_eq!(
+        simd.as_deref(),
+        Some(scalar_blur(&filter, &source).as_slice())
+    );
+
+    println!("{SIZE}x{SIZE} blur with blurX=40, blurY=40:");
+    time("simd", || {
+        black_box(apply_blur_on_cpu(
+            &filter,
+            black_box(&source),
+            SIZE as u32,
+            SIZE as u32,
+            (0, 0),
+            (SIZE as u32, SIZE as u32),
+        ));
+    });
+    time("scalar", || {
+        black_box(scalar_blur(&filter, black_box(&source)));
+    });
+}
@@ -1,9 +1,12 @@
+pub mod blur;
+
 use crate::{
     bitmap::BitmapHandle,
     pixel_bender::{PixelBenderShaderArgument, PixelBenderShaderHandle},
 };
 use downcast_rs::{impl_downcast, Downcast};
 use std::fmt::Debug;
+use std::sync::Arc;
 use swf::Color;
 
 #[derive(Debug, Clone)]
@@ -70,6 +73,16 @@ impl Filter {
             _ => false,
         }
     }
+
+    /// Checks if this filter always produces a fully transparent result from a fully
+    /// transparent source, in which case applying it to such a source can be skipped.
+    pub fn preserves_transparency(&self) -> bool {
+        // TODO: There's more cases here, such as non-knockout drop shadows and glows.
+        match self {
+            Filter::BlurFilter(_) => true,
+            filter => filter.impotent(),
+        }
+    }
 }
 
 impl From<&swf::Filter> for Filter {
@@ -97,6 +110,27 @@ impl From<&swf::Filter> for Filter {
     }
 }
 
+/// Filters are equal if all of their parameters are. Like in Flash Player, the
+/// fixed-point parameters of SWF filters are compared exactly.
+///
+/// Shaders can't be compared, so shader filters are never equal to anything.
+impl PartialEq for Filter {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Filter::BevelFilter(a), Filter::BevelFilter(b)) => a == b,
+            (Filter::BlurFilter(a), Filter::BlurFilter(b)) => a == b,
+            (Filter::ColorMatrixFilter(a), Filter::ColorMatrixFilter(b)) => a == b,
+            (Filter::ConvolutionFilter(a), Filter::ConvolutionFilter(b)) => a == b,
+            (Filter::DisplacementMapFilter(a), Filter::DisplacementMapFilter(b)) => a == b,
+            (Filter::DropShadowFilter(a), Filter::DropShadowFilter(b)) => a == b,
+            (Filter::GlowFilter(a), Filter::GlowFilter(b)) => a == b,
+            (Filter::GradientBevelFilter(a), Filter::GradientBevelFilter(b)) => a == b,
+            (Filter::GradientGlowFilter(a), Filter::GradientGlowFilter(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
 impl Default for Filter {
     fn default() -> Self {
         // A default colormatrix is a filter that essentially does nothing,
@@ -142,6 +176,31 @@ impl DisplacementMapFilter {
     }
 }
 
+impl PartialEq for DisplacementMapFilter {
+    fn eq(&self, other: &Self) -> bool {
+        // The map is shared by reference, like the `BitmapData` it comes from.
+        let same_map = match (&self.map_bitmap, &other.map_bitmap) {
+            (Some(a), Some(b)) => std::ptr::eq(
+                Arc::as_ptr(&a.0).cast::<()>(),
+                Arc::as_ptr(&b.0).cast::<()>(),
+            ),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_map
+            && self.color == other.color
+            && self.component_x == other.component_x
+            && self.component_y == other.component_y
+            && self.map_point == other.map_point
+            && self.mode == other.mode
+            && self.scale_x == other.scale_x
+            && self.scale_y == other.scale_y
+            && self.viewscale_x == other.viewscale_x
+            && self.viewscale_y == other.viewscale_y
+    }
+}
+
 impl Default for DisplacementMapFilter {
     fn default() -> Self {
         Self {
@@ -158,3 +217,52 @@ impl Default for DisplacementMapFilter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swf::{BlurFilterFlags, DropShadowFilterFlags, Fixed16, Fixed8};
+
+    fn drop_shadow(angle: Fixed16) -> Filter {
+        Filter::DropShadowFilter(swf::DropShadowFilter {
+            color: Color::BLACK,
+            blur_x: Fixed16::from_f32(4.0),
+            blur_y: Fixed16::from_f32(4.0),
+            angle,
+            distance: Fixed16::from_f32(4.0),
+            strength: Fixed8::ONE,
+            flags: DropShadowFilterFlags::from_passes(1),
+        })
+    }
+
+    #[test]
+    fn equal_filters() {
+        let blur = Filter::BlurFilter(swf::BlurFilter {
+            blur_x: Fixed16::from_f32(4.0),
+            blur_y: Fixed16::from_f32(2.0),
+            flags: BlurFilterFlags::from_passes(2),
+        });
+        assert_eq!(blur, blur.clone());
+
+        let angle = Fixed16::from_f32(0.785);
+        assert_eq!(drop_shadow(angle), drop_shadow(angle));
+        assert_eq!(
+            Filter::DisplacementMapFilter(DisplacementMapFilter::default()),
+            Filter::DisplacementMapFilter(DisplacementMapFilter::default())
+        );
+    }
+
+    #[test]
+    fn unequal_filters() {
+        let angle = Fixed16::from_f32(0.785);
+        let next_angle = Fixed16::from_bits(angle.get() + 1);
+        assert_ne!(drop_shadow(angle), drop_shadow(next_angle));
+
+        let displacement = DisplacementMapFilter::default();
+        let mut scaled = displacement.clone();
+        scaled.scale_x = f32::EPSILON;
+        assert_ne!(displacement, scaled);
+
+        assert_ne!(Filter::default(), drop_shadow(angle));
+    }
+}
@@ -0,0 +1,305 @@
+use swf::{BlurFilter, Rectangle};
+use wide::f32x4;
+
+/// How much each pass should multiply the requested blur size by - accumulative.
+/// These are very approximate to Flash, and not 100% exact.
+/// Pass 1 would be 100%, but pass 2 would be 110%.
+/// This is accumulative so you can calculate the size upfront for how many passes you'll need to perform.
+pub const PASS_SCALES: [f32; 15] = [
+    1.0, 2.1, 2.7, 3.1, 3.5, 3.8, 4.0, 4.2, 4.4, 4.6, 5.0, 6.0, 6.0, 7.0, 7.0,
+];
+
+/// A single one-dimensional box blur pass, as performed by both the GPU and CPU implementations.
+///
+/// See this article for additional information on the fractional blur algorithm, as this
+/// implementation was inspired by it: https://fgiesen.wordpress.com/2012/08/01/fast-blurs-2/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlurPass {
+    /// Whether this pass blurs along the x axis (as opposed to the y axis).
+    pub horizontal: bool,
+
+    /// Full width of the kernel (left edge to right edge).
+    pub full_size: f32,
+
+    /// How many simple double-1 weighted pixel pairs can be sampled in the center.
+    pub m: f32,
+
+    /// The weight of the outermost pixel on either side of the kernel.
+    pub alpha: f32,
+}
+
+impl BlurPass {
+    fn new(horizontal: bool, full_size: f32) -> Option<Self> {
+        if full_size <= 1.0 {
+            // A width of 1 or less is a noop (it'd just sample itself and nothing else)
+            return None;
+        }
+
+        // This is how much the blur "extends past" the center pixel to either side.
+        let radius = (full_size - 1.0) / 2.0;
+
+        // Note how we're not using floor() here. This is to guarantee that alpha is not 0 when
+        // radius is a whole number: That would cause the division in the GPU implementation to
+        // end the universe, and more importantly, also waste at least one sampling of the texture.
+        // This way, alpha is 1 instead in those cases (with m being one smaller), and the last
+        // two samplings can be fused into one, at the right place and with the right weight.
+        let m = radius.ceil() - 1.0;
+        // Not the transparency kind. It's almost the fractional part of radius.
+        // If radius is a whole number, however, it's 1 instead of 0.
+        // The rounding is done to imitate the fixed-point calculations in Flash Player,
+        // improving emulation accuracy somewhat.
+        let alpha = ((radius - m) * 255.0).floor() / 255.0;
+
+        Some(Self {
+            horizontal,
+            full_size,
+            m,
+            alpha,
+        })
+    }
+}
+
+/// Returns every pass needed to apply the given filter, in order.
+///
+/// Passes which would have no effect are skipped.
+pub fn passes(filter: &BlurFilter) -> impl Iterator<Item = BlurPass> {
+    let blur_x = filter.blur_x.to_f32().min(255.0);
+    let blur_y = filter.blur_y.to_f32().min(255.0);
+    let mut last_scale_total = 0.0;
+    PASS_SCALES
+        .into_iter()
+        .take(filter.num_passes() as usize)
+        .flat_map(move |current_scale_total| {
+            let pass_scale = current_scale_total - last_scale_total;
+            last_scale_total = current_scale_total;
+            [
+                BlurPass::new(true, blur_x * pass_scale),
+                BlurPass::new(false, blur_y * pass_scale),
+            ]
+        })
+        .flatten()
+}
+
+/// Calculates the area needed to hold the result of blurring an area of the given size.
+pub fn calculate_dest_rect(filter: &BlurFilter, source_rect: Rectangle<i32>) -> Rectangle<i32> {
+    let scale = PASS_SCALES[filter.num_passes().clamp(1, 15) as usize - 1];
+    let x = (scale * filter.blur_x.to_f32()).ceil().max(0.0) as i32;
+    let y = (scale * filter.blur_y.to_f32()).ceil().max(0.0) as i32;
+    Rectangle {
+        x_min: source_rect.x_min - x,
+        x_max: source_rect.x_max + x,
+        y_min: source_rect.y_min - y,
+        y_max: source_rect.y_max + y,
+    }
+}
+
+/// Blurs an area of premultiplied RGBA pixels on the CPU.
+///
+/// The area of `source` starting at `source_point` with the size `source_size` is blurred into
+/// a new buffer of `source_size`. Like the GPU implementation, the first pass may sample pixels
+/// from outside of that area, clamped to the edges of `source`.
+///
+/// Returns `None` if the filter would have no effect.
+pub fn apply_blur_on_cpu(
+    filter: &BlurFilter,
+    source: &[u8],
+    source_width: u32,
+    source_height: u32,
+    source_point: (u32, u32),
+    source_size: (u32, u32),
+) -> Option<Vec<u8>> {
+    let width = source_size.0 as usize;
+    let height = source_size.1 as usize;
+    if width == 0 || height == 0 || source_width == 0 || source_height == 0 {
+        return None;
+    }
+
+    let load_source = |x: isize, y: isize| {
+        let x = clamp_index(source_point.0 as isize + x, source_width as usize);
+        let y = clamp_index(source_point.1 as isize + y, source_height as usize);
+        let i = (y * source_width as usize + x) * 4;
+        f32x4::new([
+            source[i] as f32,
+            source[i + 1] as f32,
+            source[i + 2] as f32,
+            source[i + 3] as f32,
+        ])
+    };
+
+    // Until the first pass is done, pixels are read straight out of `source`.
+    let mut image: Option<Vec<f32x4>> = None;
+    for pass in passes(filter) {
+        let (line_length, line_count) = if pass.horizontal {
+            (width, height)
+        } else {
+            (height, width)
+        };
+        let mut result = vec![f32x4::ZERO; width * height];
+        let mut line = vec![f32x4::ZERO; line_length];
+
+        for j in 0..line_count {
+            let sample = |i: isize| {
+                let (x, y) = if pass.horizontal {
+                    (i, j as isize)
+                } else {
+                    (j as isize, i)
+                };
+                match &image {
+                    Some(image) => image[clamp_index(y, height) * width + clamp_index(x, width)],
+                    None => load_source(x, y),
+                }
+            };
+            blur_line(&pass, sample, &mut line);
+
+            for (i, pixel) in line.iter().enumerate() {
+                let index = if pass.horizontal {
+                    j * width + i
+                } else {
+                    i * width + j
+                };
+                result[index] = *pixel;
+            }
+        }
+
+        image = Some(result);
+    }
+
+    image.map(|image| {
+        image
+            .into_iter()
+            .flat_map(|pixel| pixel.to_array().map(|channel| channel as u8))
+            .collect()
+    })
+}
+
+/// Blurs a single row or column of pixels, using a sliding window over the pixels of weight 1.
+///
+/// This matches the sampling done by `blur.wgsl`: Each output pixel is the sum of the
+/// `2 * m + 1` pixels centered on it, plus the next pixel on each side with a weight of `alpha`.
+fn blur_line(pass: &BlurPass, sample: impl Fn(isize) -> f32x4, output: &mut [f32x4]) {
+    let m = pass.m as isize;
+    let alpha = f32x4::splat(pass.alpha);
+    // `alpha` is rounded down to a multiple of 1/255, so the weights can add up to slightly less
+    // than `full_size`. Dividing by their actual sum keeps flat areas from getting darker.
+    let scale = f32x4::splat(1.0 / (2.0 * pass.m + 1.0 + 2.0 * pass.alpha));
+
+    let mut window = (-m..=m).fold(f32x4::ZERO, |sum, i| sum + sample(i));
+    for (x, pixel) in output.iter_mut().enumerate() {
+        let x = x as isize;
+        let outer = sample(x - m - 1);
+        let next = sample(x + m + 1);
+        let total = window + (outer + next) * alpha;
+        *pixel = (total * scale).round();
+        window = window + next - sample(x - m);
+    }
+}
+
+fn clamp_index(index: isize, length: usize) -> usize {
+    index.clamp(0, length as isize - 1) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swf::{BlurFilterFlags, Color, Fixed16, Fixed8, GlowFilter, GlowFilterFlags};
+
+    fn blur_filter(blur_x: f32, blur_y: f32, passes: u8) -> BlurFilter {
+        BlurFilter {
+            blur_x: Fixed16::from_f32(blur_x),
+            blur_y: Fixed16::from_f32(blur_y),
+            flags: BlurFilterFlags::from_passes(passes),
+        }
+    }
+
+    #[test]
+    fn noop_blur() {
+        let filter = blur_filter(1.0, 0.0, 1);
+        let source = [255; 4 * 4];
+        assert_eq!(passes(&filter).count(), 0);
+        assert_eq!(
+            apply_blur_on_cpu(&filter, &source, 2, 2, (0, 0), (2, 2)),
+            None
+        );
+    }
+
+    #[test]
+    fn solid_color_is_unchanged() {
+        let filter = blur_filter(8.0, 4.0, 1);
+        let source: Vec<u8> = [64, 128, 32, 255].repeat(16 * 16);
+        let result = apply_blur_on_cpu(&filter, &source, 16, 16, (0, 0), (16, 16));
+        assert_eq!(result, Some(source));
+    }
+
+    #[test]
+    fn horizontal_blur_spreads_evenly() {
+        // A 3 wide kernel has a weight of 1 for each pixel, and nothing beyond them.
+        let filter = blur_filter(3.0, 0.0, 1);
+        let mut source = vec![0; 7 * 4];
+        source[3 * 4..4 * 4].copy_from_slice(&[255, 255, 255, 255]);
+        let result = apply_blur_on_cpu(&filter, &source, 7, 1, (0, 0), (7, 1)).unwrap();
+        let alphas: Vec<u8> = result.chunks(4).map(|pixel| pixel[3]).collect();
+        assert_eq!(alphas, [0, 0, 85, 85, 85, 0, 0]);
+    }
+
+    #[test]
+    fn transparent_source_stays_transparent() {
+        let filter = blur_filter(16.0, 16.0, 3);
+        let source = [0; 16 * 16 * 4];
+        let result = apply_blur_on_cpu(&filter, &source, 16, 16, (0, 0), (16, 16));
+        assert_eq!(result, Some(source.to_vec()));
+        assert!(crate::filters::Filter::BlurFilter(filter).preserves_transparency());
+    }
+
+    #[test]
+    fn dest_rect_grows_by_blur() {
+        let filter = blur_filter(4.0, 2.0, 2);
+        let rect = calculate_dest_rect(
+            &filter,
+            Rectangle {
+                x_min: 0,
+                x_max: 10,
+                y_min: 0,
+                y_max: 10,
+            },
+        );
+        assert_eq!(
+            rect,
+            Rectangle {
+                x_min: -9,
+                x_max: 19,
+                y_min: -5,
+                y_max: 15,
+            }
+        );
+    }
+
+    #[test]
+    fn dest_rect_accumulates_across_filters() {
+        // Each filter in the stack grows the area left by the ones before it.
+        let blur = blur_filter(4.0, 2.0, 2);
+        let glow = GlowFilter {
+            color: Color::WHITE,
+            blur_x: Fixed16::from_f32(6.0),
+            blur_y: Fixed16::from_f32(6.0),
+            strength: Fixed8::ONE,
+            flags: GlowFilterFlags::from_passes(1),
+        };
+        let rect = Rectangle {
+            x_min: 0,
+            x_max: 10,
+            y_min: 0,
+            y_max: 10,
+        };
+        let rect = calculate_dest_rect(&blur, rect);
+        let rect = calculate_dest_rect(&glow.inner_blur_filter(), rect);
+        assert_eq!(
+            rect,
+            Rectangle {
+                x_min: -15,
+                x_max: 25,
+                y_min: -11,
+                y_max: 21,
+            }
+        );
+    }
+}
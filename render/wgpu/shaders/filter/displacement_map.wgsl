@@ -12,6 +12,8 @@ struct Filter {
     offset_y: f32,
     viewscale_x: f32,
     viewscale_y: f32,
+    // Number of samples taken along each axis per pixel. 1 is a single point sample.
+    samples: u32,
 }
 
 @group(0) @binding(0) var source_texture: texture_2d<f32>;
@@ -41,7 +43,7 @@ fn main_vertex(in: VertexInput) -> VertexOutput {
 }
 
 fn unpack_components(packed: u32) -> vec2<u32> {
-    return vec2<u32>(packed >> 8u, packed & 15u);
+    return vec2<u32>((packed >> 8u) & 255u, packed & 255u);
 }
 
 fn get_component(map: vec4<f32>, component: u32) -> f32 {
@@ -71,14 +73,13 @@ fn displace_coordinates(original: vec2<f32>, map: vec4<f32>, components: vec2<u3
     );
 }
 
-@fragment
-fn main_fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+fn sample_displaced(uv: vec2<f32>) -> vec4<f32> {
     let source_size = vec2<f32>(filter_args.source_width, filter_args.source_height);
     let map_size = vec2<f32>(filter_args.map_width, filter_args.map_height);
 
     var source_pos = vec2<f32>(
-        in.uv.x * filter_args.source_width,
-        in.uv.y * filter_args.source_height,
+        uv.x * filter_args.source_width,
+        uv.y * filter_args.source_height,
     );
     var map_uv = vec2<f32>(
         (source_pos.x - filter_args.offset_x) / filter_args.viewscale_x / filter_args.map_width,
@@ -88,8 +89,9 @@ fn main_fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     let viewscale = vec2<f32>(filter_args.viewscale_x, filter_args.viewscale_y);
 
     var map = textureSample(map_texture, map_sampler, map_uv);
-    if (map_uv.x < 0.0 || map_uv.x > 1.0 || map_uv.y < 0.0 || map_uv.y > 1.0) {
-        map = vec4<f32>(0.5, 0.5, 0.5, 0.5);
+    if (map_uv.x < 0.0 || map_uv.x >= 1.0 || map_uv.y < 0.0 || map_uv.y >= 1.0) {
+        // Outside of the map there's no displacement, which is a component value of exactly 128.
+        map = vec4<f32>(128.0 / 255.0);
     }
     let components = unpack_components(filter_args.components);
     let displaced = displace_coordinates(source_pos, map, components, viewscale * (vec2<f32>(filter_args.scale_x, filter_args.scale_y)));
@@ -103,7 +105,7 @@ fn main_fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     if (filter_args.mode == 1u) { // clamp
         displaced_uv = saturate(displaced_uv);
     } else if (filter_args.mode == 2u && out_of_bounds) { // ignore
-        displaced_uv = in.uv;
+        displaced_uv = uv;
     }
     var result = textureSample(source_texture, source_sampler, displaced_uv);
     if (filter_args.mode == 3u && out_of_bounds) { // color
@@ -112,3 +114,23 @@ fn main_fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     }
     return result;
 }
+
+@fragment
+fn main_fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    if (filter_args.samples <= 1u) {
+        return sample_displaced(in.uv);
+    }
+
+    // Spread a grid of samples evenly over the area of this pixel, and average them.
+    // This smooths out the aliasing caused by steep gradients in the map.
+    let source_size = vec2<f32>(filter_args.source_width, filter_args.source_height);
+    let samples = f32(filter_args.samples);
+    var total = vec4<f32>(0.0);
+    for (var y = 0u; y < filter_args.samples; y += 1u) {
+        for (var x = 0u; x < filter_args.samples; x += 1u) {
+            let offset = (vec2<f32>(f32(x), f32(y)) + 0.5) / samples - 0.5;
+            total += sample_displaced(in.uv + offset / source_size);
+        }
+    }
+    return total / (samples * samples);
+}
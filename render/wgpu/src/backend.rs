@@ -552,6 +552,9 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                 // so that we can safely start by using the actual CAB texture.
                 // It's guaranteed that at least one filter would have used it and moved the target to something else,
                 // letting us safely copy back to it later.
+                // If nothing is drawn onto a transparent background, we know that the
+                // filter source is empty without having to read it back.
+                let mut is_transparent = entry.commands.commands.is_empty() && entry.clear.a == 0;
                 let mut target = surface.draw_commands(
                     RenderTargetMode::ExistingWithColor(
                         texture.texture.clone(),
@@ -573,13 +576,19 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                     &mut self.offscreen_texture_pool,
                 );
                 for filter in entry.filters {
+                    let preserves_transparency = filter.preserves_transparency();
                     target = self.descriptors.filters.apply(
                         &self.descriptors,
                         &mut draw_encoder,
                         &mut self.offscreen_texture_pool,
-                        FilterSource::for_entire_texture(target.color_texture()),
+                        FilterSource {
+                            is_transparent,
+                            ..FilterSource::for_entire_texture(target.color_texture())
+                        },
                         filter,
+                        self.surface.quality(),
                     );
+                    is_transparent &= preserves_transparency;
                 }
                 run_copy_pipeline(
                     &self.descriptors,
@@ -874,8 +883,10 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                 texture: &source_texture.texture,
                 point: source_point,
                 size: source_size,
+                is_transparent: false,
             },
             filter,
+            self.surface.quality(),
         );
         draw_encoder.copy_texture_to_texture(
             wgpu::ImageCopyTexture {
@@ -1,3 +1,7 @@
+/// Every combination of address mode and filtering we sample textures with.
+///
+/// Filters and other internal passes should pick the sampler they need by name,
+/// while `get_sampler` maps the bitmap fill flags used by drawing code.
 #[derive(Debug)]
 pub struct BitmapSamplers {
     pub repeat_linear: wgpu::Sampler,
@@ -7,8 +7,10 @@ mod glow;
 mod shader;
 
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Mutex, OnceLock};
 
+use crate::backend::RenderTargetMode;
 use crate::buffer_pool::TexturePool;
 use crate::descriptors::Descriptors;
 use crate::filters::bevel::BevelFilter;
@@ -21,6 +23,7 @@ use crate::filters::shader::ShaderFilter;
 use crate::surface::target::CommandTarget;
 use bytemuck::{Pod, Zeroable};
 use ruffle_render::filters::Filter;
+use ruffle_render::quality::StageQuality;
 use swf::Rectangle;
 use wgpu::util::DeviceExt;
 use wgpu::vertex_attr_array;
@@ -30,6 +33,10 @@ pub struct FilterSource<'a> {
     pub texture: &'a wgpu::Texture,
     pub point: (u32, u32),
     pub size: (u32, u32),
+
+    /// Whether the source area is already known to be fully transparent, without
+    /// having to read it back from the GPU (e.g. because nothing was drawn into it).
+    pub is_transparent: bool,
 }
 
 impl<'a> FilterSource<'a> {
@@ -38,9 +45,15 @@ impl<'a> FilterSource<'a> {
             texture,
             point: (0, 0),
             size: (texture.width(), texture.height()),
+            is_transparent: false,
         }
     }
 
+    /// Whether there's nothing visible in the source area for a filter to work with.
+    pub fn is_empty(&self) -> bool {
+        self.is_transparent || self.size.0 == 0 || self.size.1 == 0
+    }
+
     pub fn vertices(&self, device: &wgpu::Device) -> wgpu::Buffer {
         let source_width = self.texture.width() as f32;
         let source_height = self.texture.height() as f32;
@@ -210,10 +223,21 @@ pub struct Filters {
     pub glow: GlowFilter,
     pub bevel: BevelFilter,
     pub displacement_map: DisplacementMapFilter,
+
+    /// The largest width or height of a source that filters will be applied to.
+    max_size: AtomicU32,
+
+    /// Whether sources larger than `max_size` are cropped to fit and then filtered.
+    /// Otherwise, the filter is skipped and the cropped source is passed through unchanged.
+    clamp_oversized: AtomicBool,
+
+    /// The device's own texture size limit, which `max_size` can never exceed.
+    device_max_size: u32,
 }
 
 impl Filters {
     pub fn new(device: &wgpu::Device) -> Self {
+        let device_max_size = device.limits().max_texture_dimension_2d;
         Self {
             blur: BlurFilter::new(device),
             color_matrix: ColorMatrixFilter::new(device),
@@ -221,9 +245,26 @@ impl Filters {
             glow: GlowFilter::new(device),
             bevel: BevelFilter::new(device),
             displacement_map: DisplacementMapFilter::new(device),
+            max_size: AtomicU32::new(device_max_size),
+            clamp_oversized: AtomicBool::new(false),
+            device_max_size,
         }
     }
 
+    /// Sets the largest width or height of a source that filters will be applied to.
+    ///
+    /// This is limited to the largest texture the device supports.
+    pub fn set_max_size(&self, max_size: u32) {
+        self.max_size
+            .store(max_size.min(self.device_max_size), Ordering::Relaxed);
+    }
+
+    /// Sets whether oversized sources are cropped and filtered, instead of skipping the filter.
+    pub fn set_clamp_oversized(&self, clamp_oversized: bool) {
+        self.clamp_oversized
+            .store(clamp_oversized, Ordering::Relaxed);
+    }
+
     pub fn calculate_dest_rect(
         &self,
         filter: &Filter,
@@ -254,88 +295,116 @@ impl Filters {
         descriptors: &Descriptors,
         draw_encoder: &mut wgpu::CommandEncoder,
         texture_pool: &mut TexturePool,
-        source: FilterSource,
-        filter: Filter,
+        mut source: FilterSource,
+        mut filter: Filter,
+        quality: StageQuality,
     ) -> CommandTarget {
-        let target =
-            match filter {
-                Filter::ColorMatrixFilter(filter) => Some(descriptors.filters.color_matrix.apply(
-                    descriptors,
-                    texture_pool,
-                    draw_encoder,
-                    &source,
-                    &filter,
-                )),
-                Filter::BlurFilter(filter) => descriptors.filters.blur.apply(
-                    descriptors,
-                    texture_pool,
-                    draw_encoder,
-                    &source,
-                    &filter,
-                ),
-                Filter::ShaderFilter(shader) => Some(descriptors.filters.shader.apply(
-                    descriptors,
-                    texture_pool,
-                    draw_encoder,
-                    &source,
-                    shader,
-                )),
-                Filter::GlowFilter(filter) => Some(descriptors.filters.glow.apply(
-                    descriptors,
-                    texture_pool,
-                    draw_encoder,
-                    &source,
-                    &filter,
-                    &self.blur,
-                    (0.0, 0.0),
-                )),
-                Filter::DropShadowFilter(filter) => Some(DropShadowFilter::apply(
-                    descriptors,
-                    texture_pool,
-                    draw_encoder,
-                    &source,
-                    &filter,
-                    &self.blur,
-                    &self.glow,
-                )),
-                Filter::BevelFilter(filter) => Some(descriptors.filters.bevel.apply(
-                    descriptors,
-                    texture_pool,
-                    draw_encoder,
-                    &source,
-                    &filter,
-                    &self.blur,
-                )),
-                Filter::DisplacementMapFilter(filter) => descriptors
-                    .filters
-                    .displacement_map
-                    .apply(descriptors, texture_pool, draw_encoder, &source, &filter),
-                filter => {
-                    static WARNED_FILTERS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
-                    let name = match filter {
-                        Filter::GradientGlowFilter(_) => "GradientGlowFilter",
-                        Filter::GradientBevelFilter(_) => "GradientBevelFilter",
-                        Filter::ConvolutionFilter(_) => "ConvolutionFilter",
-                        Filter::ColorMatrixFilter(_)
-                        | Filter::BlurFilter(_)
-                        | Filter::GlowFilter(_)
-                        | Filter::DropShadowFilter(_)
-                        | Filter::BevelFilter(_)
-                        | Filter::DisplacementMapFilter(_)
-                        | Filter::ShaderFilter(_) => unreachable!(),
-                    };
-                    // Only warn once per filter type
-                    if WARNED_FILTERS
-                        .get_or_init(Default::default)
-                        .lock()
-                        .unwrap()
-                        .insert(name)
-                    {
-                        tracing::warn!("Unsupported filter {filter:?}");
+        let max_size = self.max_size.load(Ordering::Relaxed);
+        if source.size.0 > max_size || source.size.1 > max_size {
+            // Every filter needs a working texture as large as its source, which we can't allocate.
+            let clamp_oversized = self.clamp_oversized.load(Ordering::Relaxed);
+            static WARNED_OVERSIZED: AtomicBool = AtomicBool::new(false);
+            if !WARNED_OVERSIZED.swap(true, Ordering::Relaxed) {
+                tracing::warn!(
+                    "Filter source of {}x{} exceeds the maximum size of {max_size}, {}",
+                    source.size.0,
+                    source.size.1,
+                    if clamp_oversized {
+                        "cropping it"
+                    } else {
+                        "skipping the filter"
                     }
-                    None
+                );
+            }
+            source.size = (source.size.0.min(max_size), source.size.1.min(max_size));
+            if !clamp_oversized {
+                // An identity color matrix is essentially a blit.
+                filter = Filter::ColorMatrixFilter(Default::default());
+            }
+        }
+
+        let target = match filter {
+            Filter::ColorMatrixFilter(filter) => Some(descriptors.filters.color_matrix.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                &source,
+                &filter,
+            )),
+            Filter::BlurFilter(filter) => descriptors.filters.blur.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                &source,
+                &filter,
+            ),
+            Filter::ShaderFilter(shader) => Some(descriptors.filters.shader.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                &source,
+                shader,
+            )),
+            Filter::GlowFilter(filter) => Some(descriptors.filters.glow.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                &source,
+                &filter,
+                &self.blur,
+                (0.0, 0.0),
+            )),
+            Filter::DropShadowFilter(filter) => Some(DropShadowFilter::apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                &source,
+                &filter,
+                &self.blur,
+                &self.glow,
+            )),
+            Filter::BevelFilter(filter) => Some(descriptors.filters.bevel.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                &source,
+                &filter,
+                &self.blur,
+            )),
+            Filter::DisplacementMapFilter(filter) => descriptors.filters.displacement_map.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                &source,
+                &filter,
+                quality,
+            ),
+            filter => {
+                static WARNED_FILTERS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+                let name = match filter {
+                    Filter::GradientGlowFilter(_) => "GradientGlowFilter",
+                    Filter::GradientBevelFilter(_) => "GradientBevelFilter",
+                    Filter::ConvolutionFilter(_) => "ConvolutionFilter",
+                    Filter::ColorMatrixFilter(_)
+                    | Filter::BlurFilter(_)
+                    | Filter::GlowFilter(_)
+                    | Filter::DropShadowFilter(_)
+                    | Filter::BevelFilter(_)
+                    | Filter::DisplacementMapFilter(_)
+                    | Filter::ShaderFilter(_) => unreachable!(),
+                };
+                // Only warn once per filter type
+                if WARNED_FILTERS
+                    .get_or_init(Default::default)
+                    .lock()
+                    .unwrap()
+                    .insert(name)
+                {
+                    tracing::warn!("Unsupported filter {filter:?}");
                 }
-            };
+                None
+            }
+        };
 
         let target = target.unwrap_or_else(|| {
             // Apply a default color matrix - it's essentially a blit
@@ -393,6 +462,81 @@ pub const VERTEX_BUFFERS_DESCRIPTION_FILTERS_WITH_BLUR: [wgpu::VertexBufferLayou
         ],
     }];
 
+/// A pair of targets for filters that render in multiple passes.
+///
+/// Each pass reads the result of the previous pass from the front target and
+/// renders into the back target, after which the two are swapped.
+#[derive(Debug)]
+pub struct PingPong<T> {
+    front: T,
+    back: T,
+    passes: u32,
+}
+
+impl<T> PingPong<T> {
+    pub fn new(front: T, back: T) -> Self {
+        Self {
+            front,
+            back,
+            passes: 0,
+        }
+    }
+
+    /// How many passes have been completed so far.
+    pub fn passes(&self) -> u32 {
+        self.passes
+    }
+
+    /// Returns the result of the previous pass, and the target for the next one.
+    pub fn split(&mut self) -> (&T, &mut T) {
+        (&self.front, &mut self.back)
+    }
+
+    /// Completes a pass, making its target the new front.
+    pub fn swap(&mut self) {
+        std::mem::swap(&mut self.front, &mut self.back);
+        self.passes += 1;
+    }
+
+    /// Returns the result of the last pass, or `None` if no pass was completed.
+    pub fn finish(self) -> Option<T> {
+        if self.passes > 0 {
+            Some(self.front)
+        } else {
+            None
+        }
+    }
+}
+
+impl PingPong<CommandTarget> {
+    /// Allocates two transparent targets of the same size and format as the given source.
+    pub fn for_source(
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source: &FilterSource,
+    ) -> Self {
+        let mut create_target = || {
+            CommandTarget::new(
+                descriptors,
+                texture_pool,
+                wgpu::Extent3d {
+                    width: source.size.0,
+                    height: source.size.1,
+                    depth_or_array_layers: 1,
+                },
+                source.texture.format(),
+                source.texture.sample_count(),
+                RenderTargetMode::FreshWithColor(wgpu::Color::TRANSPARENT),
+                draw_encoder,
+            )
+        };
+        let front = create_target();
+        let back = create_target();
+        Self::new(front, back)
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
 pub struct FilterVertexWithDoubleBlur {
@@ -413,3 +557,41 @@ pub const VERTEX_BUFFERS_DESCRIPTION_FILTERS_WITH_DOUBLE_BLUR: [wgpu::VertexBuff
             3 => Float32x2,
         ],
     }];
+
+#[cfg(test)]
+mod tests {
+    use super::displacement_map::pack_components;
+    use super::PingPong;
+
+    #[test]
+    fn ping_pong_alternates_targets() {
+        let mut targets = PingPong::new("a", "b");
+        assert_eq!(targets.split(), (&"a", &mut "b"));
+
+        targets.swap();
+        assert_eq!(targets.passes(), 1);
+        assert_eq!(targets.split(), (&"b", &mut "a"));
+
+        targets.swap();
+        assert_eq!(targets.passes(), 2);
+        assert_eq!(targets.finish(), Some("a"));
+    }
+
+    #[test]
+    fn ping_pong_without_passes_has_no_result() {
+        let targets = PingPong::new("a", "b");
+        assert_eq!(targets.finish(), None);
+    }
+
+    #[test]
+    fn displacement_components_keep_their_channels() {
+        // Mirrors `unpack_components` in `displacement_map.wgsl`.
+        let unpack = |packed: u32| ((packed >> 8) & 255, packed & 255);
+
+        // componentX = BitmapDataChannel.RED, componentY = BitmapDataChannel.GREEN
+        assert_eq!(unpack(pack_components(1, 2)), (1, 2));
+        assert_eq!(unpack(pack_components(8, 4)), (8, 4));
+        // Channels which aren't valid flags must not bleed into the other axis.
+        assert_eq!(unpack(pack_components(0, 255)), (0, 255));
+    }
+}
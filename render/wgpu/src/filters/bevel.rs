@@ -249,7 +249,7 @@ impl BevelFilter {
                     wgpu::BindGroupEntry {
                         binding: 1,
                         resource: wgpu::BindingResource::Sampler(
-                            descriptors.bitmap_samplers.get_sampler(false, false),
+                            &descriptors.bitmap_samplers.clamp_nearest,
                         ),
                     },
                     wgpu::BindGroupEntry {
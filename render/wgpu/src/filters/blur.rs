@@ -1,23 +1,15 @@
-use crate::backend::RenderTargetMode;
 use crate::buffer_pool::TexturePool;
 use crate::descriptors::Descriptors;
-use crate::filters::{FilterSource, VERTEX_BUFFERS_DESCRIPTION_FILTERS};
+use crate::filters::{FilterSource, PingPong, VERTEX_BUFFERS_DESCRIPTION_FILTERS};
 use crate::surface::target::CommandTarget;
 use crate::utils::SampleCountMap;
 use bytemuck::{Pod, Zeroable};
+use ruffle_render::filters::blur;
 use std::sync::OnceLock;
 use swf::{BlurFilter as BlurFilterArgs, Rectangle};
 use wgpu::util::DeviceExt;
 use wgpu::{BufferSlice, CommandEncoder, RenderPipeline, TextureView};
 
-/// How much each pass should multiply the requested blur size by - accumulative.
-/// These are very approximate to Flash, and not 100% exact.
-/// Pass 1 would be 100%, but pass 2 would be 110%.
-/// This is accumulative so you can calculate the size upfront for how many passes you'll need to perform.
-const PASS_SCALES: [f32; 15] = [
-    1.0, 2.1, 2.7, 3.1, 3.5, 3.8, 4.0, 4.2, 4.4, 4.6, 5.0, 6.0, 6.0, 7.0, 7.0,
-];
-
 /// This is a 1:1 match of of `struct Filter` in `blur.wgsl`. See that, and the usage below, for more info.
 /// Since WebGL requires 16 byte struct size (alignment), some of these fields (namely m2 and last_weight)
 /// are passed in precomputed, even though they are trivial to get (addition/multiplication by constant).
@@ -148,15 +140,7 @@ impl BlurFilter {
         filter: &BlurFilterArgs,
         source_rect: Rectangle<i32>,
     ) -> Rectangle<i32> {
-        let scale = PASS_SCALES[filter.num_passes().clamp(1, 15) as usize - 1];
-        let x = (scale * filter.blur_x.to_f32()).ceil().max(0.0) as i32;
-        let y = (scale * filter.blur_y.to_f32()).ceil().max(0.0) as i32;
-        Rectangle {
-            x_min: source_rect.x_min - x,
-            x_max: source_rect.x_max + x,
-            y_min: source_rect.y_min - y,
-            y_max: source_rect.y_max + y,
-        }
+        blur::calculate_dest_rect(filter, source_rect)
     }
 
     pub fn apply(
@@ -167,149 +151,87 @@ impl BlurFilter {
         source: &FilterSource,
         filter: &BlurFilterArgs,
     ) -> Option<CommandTarget> {
-        let sample_count = source.texture.sample_count();
-        let format = source.texture.format();
-        let pipeline = self.pipeline(descriptors, sample_count);
+        if source.is_empty() {
+            // Blurring nothing results in nothing, don't bother with any of the passes
+            return None;
+        }
 
-        let mut flip = CommandTarget::new(
-            descriptors,
-            texture_pool,
-            wgpu::Extent3d {
-                width: source.size.0,
-                height: source.size.1,
-                depth_or_array_layers: 1,
-            },
-            format,
-            sample_count,
-            RenderTargetMode::FreshWithColor(wgpu::Color::TRANSPARENT),
-            draw_encoder,
-        );
-        let mut flop = CommandTarget::new(
-            descriptors,
-            texture_pool,
-            wgpu::Extent3d {
-                width: source.size.0,
-                height: source.size.1,
-                depth_or_array_layers: 1,
-            },
-            format,
-            sample_count,
-            RenderTargetMode::FreshWithColor(wgpu::Color::TRANSPARENT),
-            draw_encoder,
-        );
+        let pipeline = self.pipeline(descriptors, source.texture.sample_count());
+        let mut targets = PingPong::for_source(descriptors, texture_pool, draw_encoder, source);
 
         let vertices = source.vertices(&descriptors.device);
 
         let source_view = source.texture.create_view(&Default::default());
-        let mut first = true;
-        let mut last_scale_total = 0.0;
-        for current_scale_total in PASS_SCALES.into_iter().take(filter.num_passes() as usize) {
-            let pass_scale = current_scale_total - last_scale_total;
-            last_scale_total = current_scale_total;
-
-            for i in 0..2 {
-                let horizontal = i % 2 == 0;
-                let strength = if horizontal {
-                    filter.blur_x.to_f32()
-                } else {
-                    filter.blur_y.to_f32()
-                };
-                // Full width of the kernel (left edge to right edge)
-                let full_size = strength.min(255.0) * pass_scale;
-                if full_size <= 1.0 {
-                    // A width of 1 or less is a noop (it'd just sample itself and nothing else)
-                    continue;
-                }
-
-                let (previous_view, previous_vertices, previous_width, previous_height) = if first {
-                    first = false;
-                    (
-                        &source_view,
-                        vertices.slice(..),
-                        source.texture.width() as f32,
-                        source.texture.height() as f32,
-                    )
-                } else {
-                    (
-                        flip.color_view(),
-                        descriptors.quad.filter_vertices.slice(..),
-                        flip.width() as f32,
-                        flip.height() as f32,
-                    )
-                };
-
-                // See this article for additional information on the fractional blur algorithm, as this
-                // implementation was inspired by it: https://fgiesen.wordpress.com/2012/08/01/fast-blurs-2/
-
-                // This is how much the blur "extends past" the center pixel to either side.
-                let radius = (full_size - 1.0) / 2.0;
-
-                // This is how many simple double-1 weighted pixel pairs we can sample in the center.
-                // Note how we're not using floor() here. This is to guarantee that alpha is not 0 when
-                // radius is a whole number: That would cause the division below to end the universe,
-                // and more importantly, also waste at least one sampling of the texture (the first one).
-                // This way, alpha is 1 instead in those cases (with m being one smaller), and the last
-                // two samplings can be fused into one, at the right place and with the right weight.
-                let m = radius.ceil() - 1.0;
-                // Not the transparency kind. It's almost the fractional part of radius.
-                // If radius is a whole number, however, it's 1 instead of 0.
-                // The rounding is done to imitate the fixed-point calculations in Flash Player,
-                // improving emulation accuracy somewhat.
-                let alpha = ((radius - m) * 255.0).floor() / 255.0;
-
-                // These control how and where the last pair of pixels are to be sampled,
-                // so that the next-to-last will end up with an effective weight of 1.0,
-                // and the last one with a weight of alpha. Note that the offset is relative
-                // to the center of the next-to-last sampled pixel, in the range of 0 to 0.5.
-                let last_offset = 1.0 / ((1.0 / alpha) + 1.0);
-                let last_weight = alpha + 1.0;
+        for pass in blur::passes(filter) {
+            let is_first = targets.passes() == 0;
+            let (previous, next) = targets.split();
+            // The step between samples must be one texel of the texture being sampled, not of
+            // `source.size`: The first pass samples a sub-area of a possibly larger texture,
+            // with UVs normalized to the whole texture (see `FilterSource::vertices`).
+            let (previous_view, previous_vertices, previous_width, previous_height) = if is_first {
+                (
+                    &source_view,
+                    vertices.slice(..),
+                    source.texture.width() as f32,
+                    source.texture.height() as f32,
+                )
+            } else {
+                (
+                    previous.color_view(),
+                    descriptors.quad.filter_vertices.slice(..),
+                    previous.width() as f32,
+                    previous.height() as f32,
+                )
+            };
 
-                let uniform = BlurUniform {
-                    direction: if horizontal {
-                        [1.0 / previous_width, 0.0]
-                    } else {
-                        [0.0, 1.0 / previous_height]
-                    },
-                    full_size,
-                    m,
-                    m2: m * 2.0,
-                    first_weight: alpha,
-                    last_offset,
-                    last_weight,
-                };
+            // These control how and where the last pair of pixels are to be sampled,
+            // so that the next-to-last will end up with an effective weight of 1.0,
+            // and the last one with a weight of alpha. Note that the offset is relative
+            // to the center of the next-to-last sampled pixel, in the range of 0 to 0.5.
+            let last_offset = 1.0 / ((1.0 / pass.alpha) + 1.0);
+            let last_weight = pass.alpha + 1.0;
 
-                if descriptors.limits.max_push_constant_size > 0 {
-                    self.render_with_push_constants(
-                        descriptors,
-                        draw_encoder,
-                        pipeline,
-                        &mut flop,
-                        previous_view,
-                        previous_vertices,
-                        uniform,
-                    );
+            let uniform = BlurUniform {
+                direction: if pass.horizontal {
+                    [1.0 / previous_width, 0.0]
                 } else {
-                    self.render_with_uniform_buffers(
-                        descriptors,
-                        draw_encoder,
-                        pipeline,
-                        &mut flop,
-                        previous_view,
-                        previous_vertices,
-                        uniform,
-                    );
-                }
+                    [0.0, 1.0 / previous_height]
+                },
+                full_size: pass.full_size,
+                m: pass.m,
+                m2: pass.m * 2.0,
+                first_weight: pass.alpha,
+                last_offset,
+                last_weight,
+            };
 
-                std::mem::swap(&mut flip, &mut flop);
+            if descriptors.limits.max_push_constant_size > 0 {
+                self.render_with_push_constants(
+                    descriptors,
+                    draw_encoder,
+                    pipeline,
+                    next,
+                    previous_view,
+                    previous_vertices,
+                    uniform,
+                );
+            } else {
+                self.render_with_uniform_buffers(
+                    descriptors,
+                    draw_encoder,
+                    pipeline,
+                    next,
+                    previous_view,
+                    previous_vertices,
+                    uniform,
+                );
             }
-        }
 
-        if first {
-            // Nothing happened, don't return an empty unused texture
-            None
-        } else {
-            Some(flip)
+            targets.swap();
         }
+
+        // If nothing happened, don't return an empty unused texture
+        targets.finish()
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -336,7 +258,7 @@ impl BlurFilter {
                     wgpu::BindGroupEntry {
                         binding: 1,
                         resource: wgpu::BindingResource::Sampler(
-                            descriptors.bitmap_samplers.get_sampler(false, true),
+                            &descriptors.bitmap_samplers.clamp_linear,
                         ),
                     },
                 ],
@@ -396,7 +318,7 @@ impl BlurFilter {
                     wgpu::BindGroupEntry {
                         binding: 1,
                         resource: wgpu::BindingResource::Sampler(
-                            descriptors.bitmap_samplers.get_sampler(false, true),
+                            &descriptors.bitmap_samplers.clamp_linear,
                         ),
                     },
                     wgpu::BindGroupEntry {
@@ -148,7 +148,7 @@ impl ColorMatrixFilter {
                     wgpu::BindGroupEntry {
                         binding: 1,
                         resource: wgpu::BindingResource::Sampler(
-                            descriptors.bitmap_samplers.get_sampler(false, false),
+                            &descriptors.bitmap_samplers.clamp_nearest,
                         ),
                     },
                     wgpu::BindGroupEntry {
@@ -9,6 +9,7 @@ use bytemuck::{Pod, Zeroable};
 use ruffle_render::filters::{
     DisplacementMapFilter as DisplacementMapFilterArgs, DisplacementMapFilterMode,
 };
+use ruffle_render::quality::StageQuality;
 use std::sync::OnceLock;
 use swf::Rectangle;
 use wgpu::util::DeviceExt;
@@ -29,6 +30,8 @@ struct DisplacementMapUniform {
     offset_y: f32,
     viewscale_x: f32,
     viewscale_y: f32,
+    samples: u32,
+    _padding: [u32; 3],
 }
 
 pub struct DisplacementMapFilter {
@@ -169,6 +172,7 @@ impl DisplacementMapFilter {
         draw_encoder: &mut wgpu::CommandEncoder,
         source: &FilterSource,
         filter: &DisplacementMapFilterArgs,
+        quality: StageQuality,
     ) -> Option<CommandTarget> {
         let sample_count = source.texture.sample_count();
         let format = source.texture.format();
@@ -202,7 +206,7 @@ impl DisplacementMapFilter {
                         f32::from(filter.color.b) / 255.0,
                         f32::from(filter.color.a) / 255.0,
                     ],
-                    components: ((filter.component_x as u32) << 8) | (filter.component_y as u32),
+                    components: pack_components(filter.component_x, filter.component_y),
                     mode: match filter.mode {
                         DisplacementMapFilterMode::Wrap => 0,
                         DisplacementMapFilterMode::Clamp => 1,
@@ -215,10 +219,15 @@ impl DisplacementMapFilter {
                     source_height: source.texture.height() as f32,
                     map_width: map_texture.texture.width() as f32,
                     map_height: map_texture.texture.height() as f32,
-                    offset_x: filter.map_point.0 as f32,
-                    offset_y: filter.map_point.1 as f32,
+                    // The map point is relative to the source area, and may be negative.
+                    offset_x: source.point.0 as f32
+                        + filter.map_point.0 as f32 * filter.viewscale_x,
+                    offset_y: source.point.1 as f32
+                        + filter.map_point.1 as f32 * filter.viewscale_y,
                     viewscale_x: filter.viewscale_x,
                     viewscale_y: filter.viewscale_y,
+                    samples: samples_for_quality(quality),
+                    _padding: [0; 3],
                 }]),
                 usage: wgpu::BufferUsages::UNIFORM,
             });
@@ -240,13 +249,13 @@ impl DisplacementMapFilter {
                     wgpu::BindGroupEntry {
                         binding: 2,
                         resource: wgpu::BindingResource::Sampler(
-                            descriptors.bitmap_samplers.get_sampler(true, true),
+                            &descriptors.bitmap_samplers.repeat_linear,
                         ),
                     },
                     wgpu::BindGroupEntry {
                         binding: 3,
                         resource: wgpu::BindingResource::Sampler(
-                            descriptors.bitmap_samplers.get_sampler(false, false),
+                            &descriptors.bitmap_samplers.clamp_nearest,
                         ),
                     },
                     wgpu::BindGroupEntry {
@@ -274,3 +283,23 @@ impl DisplacementMapFilter {
         Some(target)
     }
 }
+
+/// Pack the channels used for each axis into the `components` uniform.
+///
+/// Both are `BitmapDataChannel` flags (1 red, 2 green, 4 blue, 8 alpha), which
+/// `displacement_map.wgsl` unpacks again in `unpack_components`.
+pub(super) fn pack_components(component_x: u8, component_y: u8) -> u32 {
+    ((component_x as u32) << 8) | (component_y as u32)
+}
+
+/// How many samples the displacement map filter takes along each axis per pixel.
+///
+/// Multiple samples reduce aliasing where the map has steep gradients, but are more
+/// expensive, so this is only done for the higher quality settings.
+fn samples_for_quality(quality: StageQuality) -> u32 {
+    match quality {
+        StageQuality::Low | StageQuality::Medium | StageQuality::High => 1,
+        StageQuality::Best | StageQuality::High8x8 | StageQuality::High8x8Linear => 2,
+        StageQuality::High16x16 | StageQuality::High16x16Linear => 4,
+    }
+}
@@ -208,7 +208,7 @@ impl GlowFilter {
                     wgpu::BindGroupEntry {
                         binding: 1,
                         resource: wgpu::BindingResource::Sampler(
-                            descriptors.bitmap_samplers.get_sampler(false, false),
+                            &descriptors.bitmap_samplers.clamp_nearest,
                         ),
                     },
                     wgpu::BindGroupEntry {
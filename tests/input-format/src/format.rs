@@ -22,11 +22,26 @@ pub enum MouseButton {
 /// Control inputs to a text field
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TextControlCode {
-    // TODO: Add control codes for Ctrl+Arrows and Home/End keys
     MoveLeft,
+    MoveLeftWord,
+    MoveLineStart,
     MoveRight,
+    MoveRightWord,
+    MoveLineEnd,
+    MoveUp,
+    MoveDown,
+    MovePageUp,
+    MovePageDown,
     SelectLeft,
+    SelectLeftWord,
+    SelectLineStart,
     SelectRight,
+    SelectRightWord,
+    SelectLineEnd,
+    SelectUp,
+    SelectDown,
+    SelectPageUp,
+    SelectPageDown,
     SelectAll,
     Copy,
     Paste,
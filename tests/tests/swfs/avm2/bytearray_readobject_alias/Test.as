@@ -0,0 +1,38 @@
+package {
+    import flash.display.MovieClip;
+    import flash.net.registerClassAlias;
+    import flash.utils.ByteArray;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            registerClassAlias("test.Registered", Registered);
+
+            var registered:Object = readTypedObject("test.Registered");
+            trace("///registered alias");
+            trace(registered is Registered);
+            trace(registered.x);
+
+            var unregistered:Object = readTypedObject("test.Unregistered");
+            trace("///unregistered alias");
+            trace(unregistered is Registered);
+            trace(unregistered.constructor === Object);
+            trace(unregistered.x);
+        }
+
+        // An AMF3 dynamic object of the given class alias, with `x = 5`.
+        private function readTypedObject(alias:String):Object {
+            var bytes:ByteArray = new ByteArray();
+            bytes.writeByte(0x0A);
+            bytes.writeByte(0x0B);
+            bytes.writeByte((alias.length << 1) | 1);
+            bytes.writeUTFBytes(alias);
+            bytes.writeByte(0x03);
+            bytes.writeUTFBytes("x");
+            bytes.writeByte(0x04);
+            bytes.writeByte(0x05);
+            bytes.writeByte(0x01);
+            bytes.position = 0;
+            return bytes.readObject();
+        }
+    }
+}
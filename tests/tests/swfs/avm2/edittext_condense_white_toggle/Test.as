@@ -0,0 +1,31 @@
+package {
+    import flash.display.MovieClip;
+    import flash.text.TextField;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var field:TextField = new TextField();
+            field.htmlText = "a   b";
+            trace("///htmlText = \"a   b\"");
+            trace(escape(field.text));
+            field.condenseWhite = true;
+            trace("///condenseWhite = true");
+            trace(escape(field.text));
+            trace(field.htmlText.indexOf("a   b") >= 0);
+            field.htmlText = "a   b";
+            trace("///htmlText = \"a   b\"");
+            trace(escape(field.text));
+            trace(field.htmlText.indexOf("a b") >= 0);
+
+            var other:TextField = new TextField();
+            other.condenseWhite = true;
+            other.htmlText = "c   d";
+            trace("///condenseWhite = true; htmlText = \"c   d\"");
+            trace(escape(other.text));
+            other.condenseWhite = false;
+            trace("///condenseWhite = false");
+            trace(escape(other.text));
+            trace(other.htmlText.indexOf("c d") >= 0);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+package {
+    import flash.display.MovieClip;
+    import flash.events.Event;
+    import flash.text.TextField;
+
+    public class Test extends MovieClip {
+        private var field:TextField;
+        private var frame:int = 0;
+
+        public function Test() {
+            var lines:Array = [];
+            for (var i:int = 0; i < 10; i++) {
+                lines.push("line" + i);
+            }
+
+            field = new TextField();
+            field.multiline = true;
+            field.width = 100;
+            field.height = 40;
+            field.text = lines.join("\r");
+            addChild(field);
+
+            addEventListener(Event.ENTER_FRAME, onEnterFrame);
+        }
+
+        private function onEnterFrame(event:Event):void {
+            frame++;
+            if (frame == 1) {
+                trace("///(mouse pressed on the first line, then held below the field)");
+            } else if (frame == 3) {
+                trace("///(mouse released)");
+            }
+            trace("field.scrollV: " + field.scrollV);
+
+            if (frame == 4) {
+                trace("selection starts on line 0: " + (field.getLineIndexOfChar(field.selectionBeginIndex) == 0));
+                var lastVisibleLine:int = field.bottomScrollV - 1;
+                trace("selection ends on the last visible line: " + (field.selectionEndIndex > field.getLineOffset(lastVisibleLine)
+                    && field.selectionEndIndex < field.getLineOffset(lastVisibleLine + 1)));
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+package {
+    import flash.display.MovieClip;
+    import flash.text.TextField;
+    import flash.text.TextLineMetrics;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var field:TextField = new TextField();
+            field.multiline = true;
+            field.width = 200;
+            field.height = 100;
+            field.text = "abc\rdefghijkl";
+
+            var first:TextLineMetrics = field.getLineMetrics(0);
+            var second:TextLineMetrics = field.getLineMetrics(1);
+            var firstY:Number = 2 + first.height / 2;
+            var secondY:Number = 2 + first.height + second.height / 2;
+            var belowY:Number = 2 + first.height + second.height + 10;
+
+            var index:int = field.getCharIndexAtPoint(2 + first.width / 2, firstY);
+            trace("///inside the first line");
+            trace(index >= 0 && index < 3);
+
+            index = field.getCharIndexAtPoint(2 + second.width / 2, secondY);
+            trace("///inside the second line");
+            trace(field.getLineIndexOfChar(index));
+
+            trace("///right of the short first line");
+            trace(field.getCharIndexAtPoint(2 + first.width + 20, firstY));
+
+            trace("///below the last line");
+            trace(field.getCharIndexAtPoint(2 + first.width / 2, belowY));
+
+            trace("///above and left of the text");
+            trace(field.getCharIndexAtPoint(-5, -5));
+
+            trace("///outside the field");
+            trace(field.getCharIndexAtPoint(500, 500));
+        }
+    }
+}
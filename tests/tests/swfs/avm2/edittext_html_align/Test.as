@@ -0,0 +1,41 @@
+package {
+    import flash.display.MovieClip;
+    import flash.text.TextField;
+    import flash.text.TextLineMetrics;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            test("p", "<p align=\"left\">abc</p><p align=\"center\">abc</p><p align=\"right\">abc</p><p align=\"justify\">abc</p>");
+            test("div", "<div align=\"left\">abc</div><DIV ALIGN=\"CENTER\">abc</DIV><div align=\"Right\">abc</div><div align=\"JUSTIFY\">abc</div>");
+        }
+
+        private function test(name:String, html:String):void {
+            var field:TextField = new TextField();
+            field.multiline = true;
+            field.width = 300;
+            field.height = 100;
+            field.htmlText = html;
+
+            var left:TextLineMetrics = field.getLineMetrics(0);
+            var center:TextLineMetrics = field.getLineMetrics(1);
+            var right:TextLineMetrics = field.getLineMetrics(2);
+            var justify:TextLineMetrics = field.getLineMetrics(3);
+
+            trace("///" + name + ": align of each line");
+            trace(field.getTextFormat(0, 1).align);
+            trace(field.getTextFormat(4, 5).align);
+            trace(field.getTextFormat(8, 9).align);
+            trace(field.getTextFormat(12, 13).align);
+            trace("///" + name + ": left.x");
+            trace(left.x);
+            trace("///" + name + ": center line is centered");
+            trace(Math.abs((center.x - 2) - (field.width - 2 - (center.x + center.width))) < 1);
+            trace("///" + name + ": right line ends at the right edge");
+            trace(Math.abs(right.x + right.width - (field.width - 2)) < 1);
+            trace("///" + name + ": left.x < center.x < right.x");
+            trace(left.x < center.x && center.x < right.x);
+            trace("///" + name + ": the last line of a justified paragraph starts on the left");
+            trace(justify.x);
+        }
+    }
+}
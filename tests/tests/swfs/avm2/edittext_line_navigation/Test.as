@@ -0,0 +1,74 @@
+package {
+    import flash.display.MovieClip;
+    import flash.events.Event;
+    import flash.text.TextField;
+    import flash.text.TextFieldType;
+
+    public class Test extends MovieClip {
+        private var field:TextField;
+        private var frame:int = 0;
+        private var lastCaret:int;
+
+        private static const STEPS:Array = [
+            "MoveLineStart",
+            "MoveLineEnd",
+            "MoveUp",
+            "MoveDown, MoveDown",
+            "SelectLineStart",
+            "SelectLineEnd",
+            "MoveLineStart on a wrapped line",
+            "MoveLineEnd on a wrapped line",
+            "MoveLineEnd again"
+        ];
+
+        public function Test() {
+            field = new TextField();
+            field.type = TextFieldType.INPUT;
+            field.multiline = true;
+            field.width = 200;
+            field.height = 100;
+            field.text = "alpha beta\rgamma\rdelta epsilon";
+            addChild(field);
+
+            stage.focus = field;
+            field.setSelection(13, 13);
+            trace("///field.setSelection(13, 13)");
+            traceSelection();
+
+            addEventListener(Event.ENTER_FRAME, onEnterFrame);
+        }
+
+        private function traceSelection():void {
+            trace(field.selectionBeginIndex + " " + field.selectionEndIndex);
+        }
+
+        private function onEnterFrame(event:Event):void {
+            frame++;
+            if (frame > STEPS.length) {
+                return;
+            }
+            trace("///" + STEPS[frame - 1]);
+
+            if (frame <= 6) {
+                traceSelection();
+            } else if (frame == 7) {
+                trace("at the start of line 1: " + (field.caretIndex == field.getLineOffset(1)));
+            } else if (frame == 8) {
+                trace("within line 1: " + (field.caretIndex > field.getLineOffset(1) && field.caretIndex <= field.getLineOffset(2)));
+            } else if (frame == 9) {
+                trace("caret unchanged: " + (field.caretIndex == lastCaret));
+            }
+            lastCaret = field.caretIndex;
+
+            if (frame == 6) {
+                trace("///(wrapped text, caret in the middle of line 1)");
+                field.wordWrap = true;
+                field.width = 60;
+                field.text = "aaa bbb ccc ddd eee fff";
+                trace("field.numLines >= 3: " + (field.numLines >= 3));
+                var caret:int = field.getLineOffset(1) + 1;
+                field.setSelection(caret, caret);
+            }
+        }
+    }
+}
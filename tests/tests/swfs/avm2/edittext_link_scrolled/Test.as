@@ -0,0 +1,46 @@
+package {
+    import flash.display.MovieClip;
+    import flash.events.TextEvent;
+    import flash.text.TextField;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var vertical:TextField = new TextField();
+            vertical.width = 200;
+            vertical.height = 40;
+            vertical.multiline = true;
+            var html:String = "";
+            for (var i:int = 1; i <= 8; i++) {
+                html += "<p><a href=\"event:line" + i + "\">line " + i + " link</a></p>";
+            }
+            vertical.htmlText = html;
+            vertical.scrollV = 4;
+            vertical.addEventListener(TextEvent.LINK, onLink);
+            addChild(vertical);
+
+            var horizontal:TextField = new TextField();
+            horizontal.y = 100;
+            horizontal.width = 200;
+            horizontal.height = 40;
+            var left:String = "";
+            var right:String = "";
+            for (var j:int = 0; j < 60; j++) {
+                left += "L";
+                right += "R";
+            }
+            horizontal.htmlText = "<a href=\"event:left\">" + left + "</a><a href=\"event:right\">" + right + "</a>";
+            horizontal.scrollH = horizontal.maxScrollH;
+            horizontal.addEventListener(TextEvent.LINK, onLink);
+            addChild(horizontal);
+
+            trace("///vertical.scrollV");
+            trace(vertical.scrollV);
+            trace("///horizontal.scrollH == horizontal.maxScrollH");
+            trace(horizontal.scrollH == horizontal.maxScrollH);
+        }
+
+        private function onLink(event:TextEvent):void {
+            trace("link: " + event.text);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+package {
+    import flash.display.MovieClip;
+    import flash.text.TextField;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var field:TextField = new TextField();
+            field.text = "abcdefgh";
+
+            field.replaceText(1000, 2000, "X");
+            trace("///field.replaceText(1000, 2000, \"X\")");
+            trace(field.text);
+
+            field.replaceText(5, 2, "Y");
+            trace("///field.replaceText(5, 2, \"Y\")");
+            trace(field.text);
+
+            field.replaceText(-3, 1, "Z");
+            trace("///field.replaceText(-3, 1, \"Z\")");
+            trace(field.text);
+
+            field.replaceText(3, 3, "W");
+            trace("///field.replaceText(3, 3, \"W\")");
+            trace(field.text);
+
+            field.replaceText(-5, -1, "V");
+            trace("///field.replaceText(-5, -1, \"V\")");
+            trace(field.text);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+package {
+    import flash.display.MovieClip;
+    import flash.text.TextField;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var lines:Array = [];
+            for (var i:int = 0; i < 10; i++) {
+                lines.push("line" + i);
+            }
+
+            var field:TextField = new TextField();
+            field.multiline = true;
+            field.width = 100;
+            field.height = 40;
+            field.text = lines.join("\r");
+            field.scrollV = 3;
+            field.setSelection(2, 5);
+            trace("///before: " + field.scrollV + " " + field.selectionBeginIndex + " " + field.selectionEndIndex);
+            field.text = lines.join("\r");
+            trace("///text = same text");
+            trace(field.scrollV + " " + field.selectionBeginIndex + " " + field.selectionEndIndex);
+
+            var html:String = "<p>" + lines.join("</p><p>") + "</p>";
+            var htmlField:TextField = new TextField();
+            htmlField.multiline = true;
+            htmlField.width = 100;
+            htmlField.height = 40;
+            htmlField.htmlText = html;
+            htmlField.scrollV = 4;
+            htmlField.setSelection(6, 8);
+            trace("///before: " + htmlField.scrollV + " " + htmlField.selectionBeginIndex + " " + htmlField.selectionEndIndex);
+            htmlField.htmlText = html;
+            trace("///htmlText = same html");
+            trace(htmlField.scrollV + " " + htmlField.selectionBeginIndex + " " + htmlField.selectionEndIndex);
+        }
+    }
+}
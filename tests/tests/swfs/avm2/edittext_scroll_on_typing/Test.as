@@ -0,0 +1,40 @@
+package {
+    import flash.display.MovieClip;
+    import flash.events.Event;
+    import flash.text.TextField;
+    import flash.text.TextFieldType;
+
+    public class Test extends MovieClip {
+        private var field:TextField;
+        private var frame:int = 0;
+
+        public function Test() {
+            field = new TextField();
+            field.type = TextFieldType.INPUT;
+            field.multiline = true;
+            field.wordWrap = true;
+            field.width = 60;
+            field.height = 40;
+            addChild(field);
+
+            stage.focus = field;
+            field.setSelection(0, 0);
+            trace("///field.scrollV");
+            trace(field.scrollV);
+
+            addEventListener(Event.ENTER_FRAME, onEnterFrame);
+        }
+
+        private function onEnterFrame(event:Event):void {
+            frame++;
+            if (frame == 2) {
+                trace("///after typing past the bottom");
+                trace("field.length: " + field.length);
+                trace("more lines than fit: " + (field.numLines > field.bottomScrollV - field.scrollV + 1));
+                trace("field.scrollV > 1: " + (field.scrollV > 1));
+                trace("field.scrollV == field.maxScrollV: " + (field.scrollV == field.maxScrollV));
+                trace("field.bottomScrollV == field.numLines: " + (field.bottomScrollV == field.numLines));
+            }
+        }
+    }
+}
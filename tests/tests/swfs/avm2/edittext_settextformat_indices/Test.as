@@ -0,0 +1,42 @@
+package {
+    import flash.display.MovieClip;
+    import flash.text.TextField;
+    import flash.text.TextFormat;
+
+    public class Test extends MovieClip {
+        private var field:TextField;
+
+        public function Test() {
+            field = new TextField();
+            field.text = "abcdefghij";
+
+            var bold:TextFormat = new TextFormat();
+            bold.bold = true;
+            field.setTextFormat(bold, 5);
+            trace("///field.setTextFormat(bold, 5)");
+            traceEach("bold");
+
+            var italic:TextFormat = new TextFormat();
+            italic.italic = true;
+            field.setTextFormat(italic, 2, 4);
+            trace("///field.setTextFormat(italic, 2, 4)");
+            traceEach("italic");
+
+            var underline:TextFormat = new TextFormat();
+            underline.underline = true;
+            field.setTextFormat(underline);
+            trace("///field.setTextFormat(underline)");
+            traceEach("underline");
+            trace("///field.getTextFormat().bold");
+            trace(field.getTextFormat().bold);
+        }
+
+        private function traceEach(property:String):void {
+            var values:Array = [];
+            for (var i:int = 0; i < field.length; i++) {
+                values.push(field.getTextFormat(i, i + 1)[property]);
+            }
+            trace(values.join(","));
+        }
+    }
+}
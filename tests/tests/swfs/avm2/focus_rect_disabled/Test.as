@@ -0,0 +1,43 @@
+package {
+    import flash.display.MovieClip;
+    import flash.display.Sprite;
+    import flash.display.InteractiveObject;
+    import flash.events.Event;
+
+    public class Test extends MovieClip {
+        private var lastFocus:InteractiveObject;
+
+        public function Test() {
+            var first:Sprite = square("first", 0xFF0000, 50);
+            var second:Sprite = square("second", 0x0000FF, 250);
+            second.focusRect = false;
+
+            trace("///first.focusRect");
+            trace(first.focusRect);
+            trace("///second.focusRect");
+            trace(second.focusRect);
+            trace("///stage.stageFocusRect");
+            trace(stage.stageFocusRect);
+
+            addEventListener(Event.ENTER_FRAME, function(e:Event):void {
+                if (stage.focus != lastFocus) {
+                    lastFocus = stage.focus;
+                    trace("///stage.focus: " + lastFocus.name);
+                }
+            });
+        }
+
+        private function square(name:String, color:uint, x:Number):Sprite {
+            var sprite:Sprite = new Sprite();
+            sprite.name = name;
+            sprite.graphics.beginFill(color);
+            sprite.graphics.drawRect(0, 0, 100, 100);
+            sprite.graphics.endFill();
+            sprite.x = x;
+            sprite.y = 50;
+            sprite.tabEnabled = true;
+            addChild(sprite);
+            return sprite;
+        }
+    }
+}
@@ -0,0 +1,66 @@
+package {
+    import flash.display.MovieClip;
+    import flash.display.Sprite;
+    import flash.events.Event;
+    import flash.events.FocusEvent;
+    import flash.text.TextField;
+    import flash.text.TextFieldType;
+
+    public class Test extends MovieClip {
+        private var a:TextField;
+        private var b:TextField;
+        private var c:TextField;
+        private var frame:int = 0;
+
+        public function Test() {
+            a = makeField("a", 0);
+            b = makeField("b", 30);
+            c = makeField("c", 60);
+
+            var hidden:Sprite = new Sprite();
+            hidden.name = "hidden";
+            trace("///hidden.tabChildren");
+            trace(hidden.tabChildren);
+            hidden.tabChildren = false;
+            trace("///hidden.tabChildren = false");
+            trace(hidden.tabChildren);
+            hidden.addChild(makeField("d", 90));
+            addChild(hidden);
+
+            trace("///a.tabIndex");
+            trace(a.tabIndex);
+
+            addEventListener(Event.ENTER_FRAME, onEnterFrame);
+        }
+
+        private function makeField(name:String, y:Number):TextField {
+            var field:TextField = new TextField();
+            field.name = name;
+            field.type = TextFieldType.INPUT;
+            field.y = y;
+            field.height = 20;
+            addChild(field);
+            return field;
+        }
+
+        private function onEnterFrame(event:Event):void {
+            frame++;
+            trace("frame " + frame + ": focus is " + (stage.focus ? stage.focus.name : "null"));
+            if (frame == 5) {
+                trace("///c.tabIndex = 1; a.tabIndex = 2");
+                c.tabIndex = 1;
+                a.tabIndex = 2;
+                trace(c.tabIndex);
+                trace(a.tabIndex);
+            } else if (frame == 7) {
+                trace("///a cancels keyFocusChange");
+                a.addEventListener(FocusEvent.KEY_FOCUS_CHANGE, function(e:FocusEvent):void {
+                    e.preventDefault();
+                });
+            } else if (frame == 8) {
+                trace("///escape(a.text)");
+                trace(escape(a.text));
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+package {
+    import flash.display.MovieClip;
+    import flash.display.SimpleButton;
+    import flash.display.Sprite;
+    import flash.text.TextField;
+    import flash.text.TextFieldType;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var sprite:Sprite = new Sprite();
+            trace("///sprite.tabEnabled");
+            trace(sprite.tabEnabled);
+            sprite.buttonMode = true;
+            trace("///sprite.buttonMode = true");
+            trace(sprite.tabEnabled);
+            sprite.tabEnabled = false;
+            trace("///sprite.tabEnabled = false");
+            trace(sprite.tabEnabled);
+
+            var clip:MovieClip = new MovieClip();
+            trace("///clip.tabEnabled");
+            trace(clip.tabEnabled);
+            clip.tabEnabled = true;
+            trace("///clip.tabEnabled = true");
+            trace(clip.tabEnabled);
+
+            var button:SimpleButton = new SimpleButton();
+            trace("///button.tabEnabled");
+            trace(button.tabEnabled);
+
+            var text:TextField = new TextField();
+            trace("///text.tabEnabled");
+            trace(text.tabEnabled);
+            text.type = TextFieldType.INPUT;
+            trace("///text.type = TextFieldType.INPUT");
+            trace(text.tabEnabled);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+package {
+    import flash.display.MovieClip;
+
+    public class SharedDomainClass extends MovieClip {
+    }
+}
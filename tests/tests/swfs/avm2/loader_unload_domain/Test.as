@@ -0,0 +1,51 @@
+package {
+    import flash.display.Loader;
+    import flash.display.MovieClip;
+    import flash.events.Event;
+    import flash.net.URLRequest;
+    import flash.system.ApplicationDomain;
+    import flash.system.LoaderContext;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            loadIntoChildDomain();
+        }
+
+        private function loadIntoChildDomain():void {
+            var loader:Loader = new Loader();
+            loader.contentLoaderInfo.addEventListener(Event.INIT, function(e:Event):void {
+                var domain:ApplicationDomain = loader.contentLoaderInfo.applicationDomain;
+                trace("///child domain: domain.hasDefinition(\"ChildDomainClass\")");
+                trace(domain.hasDefinition("ChildDomainClass"));
+
+                loader.unload();
+                trace("///child domain: loader.unload()");
+                trace(domain.hasDefinition("ChildDomainClass"));
+                trace(ApplicationDomain.currentDomain.hasDefinition("ChildDomainClass"));
+                trace(ApplicationDomain.currentDomain.hasDefinition("Test"));
+
+                loadIntoSharedDomain();
+            });
+            loader.load(new URLRequest("child_domain.swf"));
+            addChild(loader);
+        }
+
+        private function loadIntoSharedDomain():void {
+            var loader:Loader = new Loader();
+            loader.contentLoaderInfo.addEventListener(Event.INIT, function(e:Event):void {
+                trace("///shared domain: hasDefinition(\"SharedDomainClass\")");
+                trace(ApplicationDomain.currentDomain.hasDefinition("SharedDomainClass"));
+
+                loader.unload();
+                trace("///shared domain: loader.unload()");
+                trace(ApplicationDomain.currentDomain.hasDefinition("SharedDomainClass"));
+                trace(ApplicationDomain.currentDomain.hasDefinition("Test"));
+            });
+            loader.load(
+                new URLRequest("shared_domain.swf"),
+                new LoaderContext(false, ApplicationDomain.currentDomain)
+            );
+            addChild(loader);
+        }
+    }
+}
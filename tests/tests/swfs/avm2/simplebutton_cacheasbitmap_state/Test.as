@@ -0,0 +1,30 @@
+package {
+    import flash.display.MovieClip;
+    import flash.display.SimpleButton;
+    import flash.display.Sprite;
+    import flash.events.MouseEvent;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var button:SimpleButton = new SimpleButton(
+                square(0xFF0000),
+                square(0x00FF00),
+                square(0x0000FF),
+                square(0x000000)
+            );
+            button.cacheAsBitmap = true;
+            button.addEventListener(MouseEvent.ROLL_OVER, function(e:MouseEvent):void {
+                trace("///rollOver");
+            });
+            addChild(button);
+        }
+
+        private function square(color:uint):Sprite {
+            var sprite:Sprite = new Sprite();
+            sprite.graphics.beginFill(color);
+            sprite.graphics.drawRect(0, 0, 100, 100);
+            sprite.graphics.endFill();
+            return sprite;
+        }
+    }
+}
@@ -0,0 +1,17 @@
+package {
+    import flash.display.MovieClip;
+    import flash.events.Event;
+
+    public class Test extends MovieClip {
+        private var frames:int = 0;
+
+        public function Test() {
+            addEventListener(Event.ENTER_FRAME, onEnterFrame);
+        }
+
+        private function onEnterFrame(e:Event):void {
+            frames++;
+            trace("///enterFrame " + frames);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+package {
+    import flash.display.SimpleButton;
+
+    public class ThrowingButton extends SimpleButton {
+        public function ThrowingButton() {
+            trace("ThrowingButton constructor");
+            throw new Error("ThrowingButton failed to construct");
+        }
+    }
+}
@@ -0,0 +1,54 @@
+package {
+    import flash.display.DisplayObject;
+    import flash.display.MovieClip;
+    import flash.display.SimpleButton;
+    import flash.display.Sprite;
+    import flash.events.MouseEvent;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var container:Sprite = new Sprite();
+            container.name = "container";
+
+            var button:SimpleButton = new SimpleButton(
+                square(0xFF0000),
+                square(0xCC0000),
+                square(0x990000),
+                square(0x000000)
+            );
+            button.name = "button";
+            button.mouseEnabled = false;
+            container.addChild(button);
+
+            var sibling:Sprite = square(0x0000FF);
+            sibling.name = "sibling";
+            sibling.x = 200;
+            container.addChild(sibling);
+
+            watch(button);
+            watch(sibling);
+            container.addEventListener(MouseEvent.CLICK, function(e:MouseEvent):void {
+                trace("container: " + e.type + " target=" + e.target.name);
+                if (!button.mouseEnabled) {
+                    trace("///button.mouseEnabled = true");
+                    button.mouseEnabled = true;
+                }
+            });
+            addChild(container);
+        }
+
+        private function watch(target:DisplayObject):void {
+            target.addEventListener(MouseEvent.CLICK, function(e:MouseEvent):void {
+                trace(target.name + ": " + e.type + " target=" + e.target.name);
+            });
+        }
+
+        private function square(color:uint):Sprite {
+            var sprite:Sprite = new Sprite();
+            sprite.graphics.beginFill(color);
+            sprite.graphics.drawRect(0, 0, 100, 100);
+            sprite.graphics.endFill();
+            return sprite;
+        }
+    }
+}
@@ -0,0 +1,67 @@
+package {
+    import flash.display.DisplayObject;
+    import flash.display.MovieClip;
+    import flash.display.Shape;
+    import flash.display.SimpleButton;
+    import flash.display.Sprite;
+    import flash.events.MouseEvent;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var inner:SimpleButton = new SimpleButton(
+                square(0x00FF00, 50),
+                square(0x00CC00, 50),
+                square(0x009900, 50),
+                square(0x000000, 50)
+            );
+            inner.name = "inner";
+            watch(inner);
+
+            var outer:SimpleButton = new SimpleButton(
+                state("up", 0xFF0000, inner),
+                state("over", 0xCC0000, null),
+                state("down", 0x990000, null),
+                square(0x000000, 200)
+            );
+            outer.name = "outer";
+            watch(outer);
+            addChild(outer);
+        }
+
+        private function watch(target:DisplayObject):void {
+            var handler:Function = function(e:MouseEvent):void {
+                trace(target.name + ": " + e.type + " target=" + e.target.name);
+            };
+            target.addEventListener(MouseEvent.MOUSE_DOWN, handler);
+            target.addEventListener(MouseEvent.MOUSE_UP, handler);
+            target.addEventListener(MouseEvent.CLICK, handler);
+        }
+
+        // A button state with a plain interactive child in its bottom-right
+        // corner, and optionally a nested button in its top-left corner.
+        private function state(name:String, color:uint, button:SimpleButton):Sprite {
+            var sprite:Sprite = square(color, 200);
+            sprite.name = name;
+
+            var plain:Sprite = square(0x0000FF, 50);
+            plain.name = name + "Plain";
+            plain.x = 150;
+            plain.y = 150;
+            watch(plain);
+            sprite.addChild(plain);
+
+            if (button) {
+                sprite.addChild(button);
+            }
+            return sprite;
+        }
+
+        private function square(color:uint, size:Number):Sprite {
+            var sprite:Sprite = new Sprite();
+            sprite.graphics.beginFill(color);
+            sprite.graphics.drawRect(0, 0, size, size);
+            sprite.graphics.endFill();
+            return sprite;
+        }
+    }
+}
@@ -0,0 +1,41 @@
+package {
+    import flash.display.MovieClip;
+    import flash.text.TextField;
+    import flash.text.TextFormat;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            var inline:TextFormat = new TextFormat();
+            inline.display = "inline";
+            var block:TextFormat = new TextFormat();
+            block.display = "block";
+
+            var paragraphs:TextField = new TextField();
+            paragraphs.multiline = true;
+            paragraphs.width = 400;
+            paragraphs.text = "abc\rdef\rghi";
+            trace("///paragraphs.numLines");
+            trace(paragraphs.numLines);
+            paragraphs.setTextFormat(inline);
+            trace("///paragraphs.setTextFormat(inline)");
+            trace(paragraphs.numLines);
+            trace(paragraphs.getTextFormat().display);
+            paragraphs.setTextFormat(block, 4, 8);
+            trace("///paragraphs.setTextFormat(block, 4, 8)");
+            trace(paragraphs.numLines);
+
+            var words:TextField = new TextField();
+            words.multiline = true;
+            words.width = 400;
+            words.text = "abc def ghi";
+            trace("///words.numLines");
+            trace(words.numLines);
+            words.setTextFormat(inline, 4, 8);
+            trace("///words.setTextFormat(inline, 4, 8)");
+            trace(words.numLines);
+            words.setTextFormat(inline);
+            trace("///words.setTextFormat(inline)");
+            trace(words.numLines);
+        }
+    }
+}
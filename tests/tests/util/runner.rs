@@ -203,9 +203,33 @@ pub fn run_swf(
                 AutomatedEvent::TextControl { code } => PlayerEvent::TextControl {
                     code: match code {
                         InputTextControlCode::MoveLeft => RuffleTextControlCode::Backspace,
+                        InputTextControlCode::MoveLeftWord => RuffleTextControlCode::MoveLeftWord,
+                        InputTextControlCode::MoveLineStart => RuffleTextControlCode::MoveLineStart,
                         InputTextControlCode::MoveRight => RuffleTextControlCode::Delete,
+                        InputTextControlCode::MoveRightWord => RuffleTextControlCode::MoveRightWord,
+                        InputTextControlCode::MoveLineEnd => RuffleTextControlCode::MoveLineEnd,
+                        InputTextControlCode::MoveUp => RuffleTextControlCode::MoveUp,
+                        InputTextControlCode::MoveDown => RuffleTextControlCode::MoveDown,
+                        InputTextControlCode::MovePageUp => RuffleTextControlCode::MovePageUp,
+                        InputTextControlCode::MovePageDown => RuffleTextControlCode::MovePageDown,
                         InputTextControlCode::SelectLeft => RuffleTextControlCode::SelectLeft,
+                        InputTextControlCode::SelectLeftWord => {
+                            RuffleTextControlCode::SelectLeftWord
+                        }
+                        InputTextControlCode::SelectLineStart => {
+                            RuffleTextControlCode::SelectLineStart
+                        }
                         InputTextControlCode::SelectRight => RuffleTextControlCode::SelectRight,
+                        InputTextControlCode::SelectRightWord => {
+                            RuffleTextControlCode::SelectRightWord
+                        }
+                        InputTextControlCode::SelectLineEnd => RuffleTextControlCode::SelectLineEnd,
+                        InputTextControlCode::SelectUp => RuffleTextControlCode::SelectUp,
+                        InputTextControlCode::SelectDown => RuffleTextControlCode::SelectDown,
+                        InputTextControlCode::SelectPageUp => RuffleTextControlCode::SelectPageUp,
+                        InputTextControlCode::SelectPageDown => {
+                            RuffleTextControlCode::SelectPageDown
+                        }
                         InputTextControlCode::SelectAll => RuffleTextControlCode::SelectAll,
                         InputTextControlCode::Copy => RuffleTextControlCode::Copy,
                         InputTextControlCode::Paste => RuffleTextControlCode::Paste,
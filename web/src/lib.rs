@@ -1806,7 +1806,6 @@ fn web_key_to_codepoint(key: &str) -> Option<char> {
 
 /// Convert a web `KeyboardEvent.key` value to a Ruffle `TextControlCode`,
 /// given the states of the modifier keys. Return `None` if there is no match.
-/// TODO: Handle Ctrl+Arrows and Home/End keys
 pub fn web_to_ruffle_text_control(
     key: &str,
     ctrl_key: bool,
@@ -1831,18 +1830,58 @@ pub fn web_to_ruffle_text_control(
         match key {
             "Delete" => Some(TextControlCode::Delete),
             "Backspace" => Some(TextControlCode::Backspace),
-            "ArrowLeft" => {
+            "ArrowLeft" => match (ctrl_key, shift_key) {
+                (true, true) => Some(TextControlCode::SelectLeftWord),
+                (true, false) => Some(TextControlCode::MoveLeftWord),
+                (false, true) => Some(TextControlCode::SelectLeft),
+                (false, false) => Some(TextControlCode::MoveLeft),
+            },
+            "ArrowRight" => match (ctrl_key, shift_key) {
+                (true, true) => Some(TextControlCode::SelectRightWord),
+                (true, false) => Some(TextControlCode::MoveRightWord),
+                (false, true) => Some(TextControlCode::SelectRight),
+                (false, false) => Some(TextControlCode::MoveRight),
+            },
+            "ArrowUp" => {
                 if shift_key {
-                    Some(TextControlCode::SelectLeft)
+                    Some(TextControlCode::SelectUp)
                 } else {
-                    Some(TextControlCode::MoveLeft)
+                    Some(TextControlCode::MoveUp)
                 }
             }
-            "ArrowRight" => {
+            "ArrowDown" => {
                 if shift_key {
-                    Some(TextControlCode::SelectRight)
+                    Some(TextControlCode::SelectDown)
                 } else {
-                    Some(TextControlCode::MoveRight)
+                    Some(TextControlCode::MoveDown)
+                }
+            }
+            "PageUp" => {
+                if shift_key {
+                    Some(TextControlCode::SelectPageUp)
+                } else {
+                    Some(TextControlCode::MovePageUp)
+                }
+            }
+            "PageDown" => {
+                if shift_key {
+                    Some(TextControlCode::SelectPageDown)
+                } else {
+                    Some(TextControlCode::MovePageDown)
+                }
+            }
+            "Home" => {
+                if shift_key {
+                    Some(TextControlCode::SelectLineStart)
+                } else {
+                    Some(TextControlCode::MoveLineStart)
+                }
+            }
+            "End" => {
+                if shift_key {
+                    Some(TextControlCode::SelectLineEnd)
+                } else {
+                    Some(TextControlCode::MoveLineEnd)
                 }
             }
             _ => None,
@@ -207,3 +207,22 @@ fn str_patterns() {
     test_pattern(wide, bstr!(b"aa"), &[(2, 4), (6, 8)], None);
     test_pattern(wide, wstr!('↓''a'), &[(1, 3), (5, 7)], None);
 }
+
+#[test]
+fn word_boundaries() {
+    let s = bstr!(b"foo  bar\nbaz");
+    assert_eq!(utils::next_word_boundary(s, 0), 5);
+    assert_eq!(utils::next_word_boundary(s, 6), 9);
+    assert_eq!(utils::next_word_boundary(s, 9), 12);
+    assert_eq!(utils::next_word_boundary(s, 12), 12);
+
+    assert_eq!(utils::prev_word_boundary(s, 12), 9);
+    assert_eq!(utils::prev_word_boundary(s, 9), 5);
+    assert_eq!(utils::prev_word_boundary(s, 5), 0);
+    assert_eq!(utils::prev_word_boundary(s, 0), 0);
+
+    let wide = wstr!('↓''↓'' ''a');
+    assert_eq!(utils::next_word_boundary(wide, 0), 3);
+    assert_eq!(utils::prev_word_boundary(wide, 4), 3);
+    assert_eq!(utils::prev_word_boundary(wide, 3), 0);
+}
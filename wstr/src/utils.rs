@@ -42,6 +42,33 @@ pub fn next_char_boundary(slice: &super::WStr, pos: usize) -> usize {
     }
 }
 
+/// Gets the position of the start of the word before `pos`, skipping over any
+/// whitespace in between; this is where the caret goes on Ctrl+Left.
+pub fn prev_word_boundary(slice: &super::WStr, pos: usize) -> usize {
+    let mut pos = pos.min(slice.len());
+    while pos > 0 && swf_is_whitespace(slice.at(pos - 1)) {
+        pos -= 1;
+    }
+    while pos > 0 && !swf_is_whitespace(slice.at(pos - 1)) {
+        pos -= 1;
+    }
+    pos
+}
+
+/// Gets the position of the start of the word after `pos`, skipping over the
+/// rest of the current word and any whitespace following it; this is where the
+/// caret goes on Ctrl+Right.
+pub fn next_word_boundary(slice: &super::WStr, pos: usize) -> usize {
+    let mut pos = pos.min(slice.len());
+    while pos < slice.len() && !swf_is_whitespace(slice.at(pos)) {
+        pos += 1;
+    }
+    while pos < slice.len() && swf_is_whitespace(slice.at(pos)) {
+        pos += 1;
+    }
+    pos
+}
+
 /// Returns `true` if the given utf16 code unit is an whitespace
 /// according to the Flash Player.
 #[inline]